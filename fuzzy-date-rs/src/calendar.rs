@@ -0,0 +1,43 @@
+use chrono::NaiveDate;
+use std::collections::HashSet;
+
+/// Dates that are not business days, in addition to weekend days
+pub trait HolidayCalendar: Send + Sync {
+    fn is_holiday(&self, date: NaiveDate) -> bool;
+}
+
+/// Holiday calendar from a fixed list of dates
+#[derive(Clone, Debug, Default)]
+pub struct HolidayDates {
+    dates: HashSet<NaiveDate>,
+}
+
+impl HolidayDates {
+    pub fn new(dates: Vec<NaiveDate>) -> Self {
+        Self { dates: HashSet::from_iter(dates) }
+    }
+}
+
+impl HolidayCalendar for HolidayDates {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.dates.contains(&date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_holiday_dates() {
+        let holidays = HolidayDates::new(vec![
+            NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 26).unwrap(),
+        ]);
+
+        assert!(holidays.is_holiday(NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()));
+        assert!(holidays.is_holiday(NaiveDate::from_ymd_opt(2024, 12, 26).unwrap()));
+        assert!(!holidays.is_holiday(NaiveDate::from_ymd_opt(2024, 12, 27).unwrap()));
+        assert!(!HolidayDates::default().is_holiday(NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()));
+    }
+}
@@ -1,5 +1,22 @@
+use crate::error::ParseError;
+use crate::token::TwoDigitYear;
+#[cfg(feature = "chrono-tz")]
+use crate::token::{AmbiguousTime, SkippedTime};
 use chrono::{DateTime, Datelike, Duration, FixedOffset, Month, NaiveDate, Timelike};
+#[cfg(feature = "chrono-tz")]
+use chrono::{LocalResult, NaiveDateTime, Offset, TimeZone};
+#[cfg(feature = "chrono-tz")]
+use chrono_tz::Tz;
 use std::cmp;
+use std::ops::Range;
+
+// Longest run of days that are not business days, before giving up
+// on finding a business day
+const MAX_NON_BUSINESS_DAYS: i64 = 366;
+
+// Largest amount of business days to move by, about 380 years, as moving
+// goes through each day to check whether it's a business day
+const MAX_BUSINESS_DAYS: i64 = 100_000;
 
 #[derive(PartialEq)]
 pub(crate) enum Change {
@@ -10,22 +27,59 @@ pub(crate) enum Change {
     None,
 }
 
+/// Time zone that local times are resolved in, after they have been
+/// moved by wall clock rather than by exact duration
+#[derive(Clone, Copy, Default)]
+pub(crate) struct LocalZone {
+    #[cfg(feature = "chrono-tz")]
+    pub(crate) time_zone: Option<Tz>,
+    #[cfg(feature = "chrono-tz")]
+    pub(crate) ambiguous: AmbiguousTime,
+    #[cfg(feature = "chrono-tz")]
+    pub(crate) skipped: SkippedTime,
+}
+
+impl LocalZone {
+    /// Move datetime into time zone, keeping it the same moment in time
+    pub(crate) fn resolve_instant(&self, from_time: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+        #[cfg(feature = "chrono-tz")]
+        if let Some(time_zone) = self.time_zone {
+            return from_time.with_timezone(&time_zone).fixed_offset();
+        }
+
+        from_time
+    }
+
+    /// Move datetime into time zone, keeping the same local time
+    pub(crate) fn resolve_local(&self, from_time: DateTime<FixedOffset>) -> Result<DateTime<FixedOffset>, ParseError> {
+        #[cfg(feature = "chrono-tz")]
+        if let Some(time_zone) = self.time_zone {
+            return local_time_tz(from_time.naive_local(), time_zone, self.ambiguous, self.skipped);
+        }
+
+        Ok(from_time)
+    }
+}
+
 /// Move datetime into specified year, month and day from a basic ISO8601 value
-pub(crate) fn date_iso8601(from_time: DateTime<FixedOffset>, value: String) -> Result<DateTime<FixedOffset>, ()> {
+pub(crate) fn date_iso8601(
+    from_time: DateTime<FixedOffset>,
+    value: String,
+) -> Result<DateTime<FixedOffset>, ParseError> {
     if value.len().ne(&8) {
-        return Err(());
+        return Err(ParseError::InvalidDate);
     }
 
     let Ok(year) = value[0..4].parse::<i64>() else {
-        return Err(());
+        return Err(ParseError::InvalidDate);
     };
 
     let Ok(month) = value[4..6].parse::<i64>() else {
-        return Err(());
+        return Err(ParseError::InvalidDate);
     };
 
     let Ok(day) = value[6..8].parse::<i64>() else {
-        return Err(());
+        return Err(ParseError::InvalidDate);
     };
 
     date_ymd(from_time, year, month, day)
@@ -43,41 +97,64 @@ pub(crate) fn date_ymd(
     year: i64,
     month: i64,
     day: i64,
-) -> Result<DateTime<FixedOffset>, ()> {
+) -> Result<DateTime<FixedOffset>, ParseError> {
     let new_time = from_time.with_day(1).unwrap();
 
+    if month.lt(&1) || month.gt(&12) || day.lt(&1) || day.gt(&31) {
+        return Err(ParseError::OutOfRange);
+    }
+
     let new_time = match new_time.with_year(year as i32) {
         Some(v) => v,
-        None => return Err(()),
+        None => return Err(ParseError::OutOfRange),
     };
 
     let new_time = match new_time.with_month(month as u32) {
         Some(v) => v,
-        None => return Err(()),
+        None => return Err(ParseError::OutOfRange),
     };
 
     let new_time = match new_time.with_day(day as u32) {
         Some(v) => v,
-        None => return Err(()),
+        None => return Err(ParseError::InvalidDate),
     };
 
     Ok(new_time)
 }
 
+/// Full year from a two-digit year, e.g. 23 into 2023, in the century
+/// chosen by the policy
+pub(crate) fn full_year(current_year: i64, year: i64, policy: TwoDigitYear) -> i64 {
+    match policy {
+        TwoDigitYear::Pivot(pivot) => match year.lt(&(pivot as i64)) {
+            true => 2000 + year,
+            false => 1900 + year,
+        },
+        TwoDigitYear::Closest => {
+            let same_century = current_year - current_year.rem_euclid(100) + year;
+
+            [same_century - 100, same_century, same_century + 100]
+                .into_iter()
+                .min_by_key(|v| (v - current_year).abs())
+                .unwrap()
+        }
+    }
+}
+
 /// Move datetime into specified year and week
 pub(crate) fn date_yw(
     from_time: DateTime<FixedOffset>,
     year: i64,
     week: i64,
     start_day: i8,
-) -> Result<DateTime<FixedOffset>, ()> {
+) -> Result<DateTime<FixedOffset>, ParseError> {
     if week.lt(&1) || week.gt(&53) {
-        return Err(());
+        return Err(ParseError::OutOfRange);
     }
 
     let iso_week = match NaiveDate::from_isoywd_opt(year as i32, week as u32, chrono::Weekday::Mon) {
         Some(v) => v,
-        None => return Err(()),
+        None => return Err(ParseError::InvalidDate),
     };
 
     let new_time = date_ymd(from_time, iso_week.year() as i64, iso_week.month() as i64, iso_week.day() as i64)?;
@@ -88,12 +165,53 @@ pub(crate) fn date_yw(
     }
 }
 
+/// Move datetime into the first day of specified year and quarter
+pub(crate) fn date_yq(
+    from_time: DateTime<FixedOffset>,
+    year: i64,
+    quarter: i64,
+) -> Result<DateTime<FixedOffset>, ParseError> {
+    if quarter.lt(&1) || quarter.gt(&4) {
+        return Err(ParseError::OutOfRange);
+    }
+
+    date_ymd(from_time, year, (quarter - 1) * 3 + 1, 1)
+}
+
+/// Year and month that is given number of months after the start of
+/// fiscal year, when fiscal years are named after the calendar year
+/// they end in, e.g. FY2025 starting from July 2024
+pub(crate) fn fiscal_year_month(fiscal_year: i64, start_month: i64, months: i64) -> (i64, i64) {
+    let start_year = match start_month.gt(&1) {
+        true => fiscal_year - 1,
+        false => fiscal_year,
+    };
+
+    let month_index = start_month - 1 + months;
+    (start_year + month_index.div_euclid(12), month_index.rem_euclid(12) + 1)
+}
+
+/// Fiscal year and quarter that given year and month belong to
+pub(crate) fn into_fiscal_quarter(year: i64, month: i64, start_month: i64) -> (i64, i64) {
+    let fiscal_year = match start_month.gt(&1) && month.ge(&start_month) {
+        true => year + 1,
+        false => year,
+    };
+
+    (fiscal_year, (month - start_month).rem_euclid(12) / 3 + 1)
+}
+
+/// Quarter of the year that the month belongs to
+pub(crate) fn into_quarter(month: u32) -> i64 {
+    ((month - 1) / 3 + 1) as i64
+}
+
 /// Return time set to the last day of given year and month
 pub(crate) fn into_last_of_month(
     from_time: DateTime<FixedOffset>,
     year: i64,
     month: i64,
-) -> Result<DateTime<FixedOffset>, ()> {
+) -> Result<DateTime<FixedOffset>, ParseError> {
     let last_day = into_month_day(year as i32, month as u32, 31) as i64;
     date_ymd(from_time, year, month, last_day)
 }
@@ -177,7 +295,7 @@ pub(crate) fn offset_range_year_month(
     year: i64,
     month: i64,
     change: Change,
-) -> Result<DateTime<FixedOffset>, ()> {
+) -> Result<DateTime<FixedOffset>, ParseError> {
     if change.eq(&Change::First) {
         return date_ymd(from_time, year, month, 1);
     }
@@ -189,6 +307,24 @@ pub(crate) fn offset_range_year_month(
     Ok(from_time)
 }
 
+/// Move datetime into first or last day of months within fiscal year,
+/// e.g. months 0..3 for the first quarter of the fiscal year
+pub(crate) fn offset_range_fiscal(
+    from_time: DateTime<FixedOffset>,
+    fiscal_year: i64,
+    start_month: i64,
+    months: Range<i64>,
+    change: Change,
+) -> Result<DateTime<FixedOffset>, ParseError> {
+    let month_offset = match change {
+        Change::Last => months.end - 1,
+        _ => months.start,
+    };
+
+    let (year, month) = fiscal_year_month(fiscal_year, start_month, month_offset);
+    offset_range_year_month(from_time, year, month, change)
+}
+
 /// Move datetime into first or last weekday of specified year and month
 pub(crate) fn offset_range_year_month_wday(
     from_time: DateTime<FixedOffset>,
@@ -196,7 +332,7 @@ pub(crate) fn offset_range_year_month_wday(
     month: i64,
     wday: i64,
     change: Change,
-) -> Result<DateTime<FixedOffset>, ()> {
+) -> Result<DateTime<FixedOffset>, ParseError> {
     if change.eq(&Change::First) {
         let from_time = date_ymd(from_time, year, month, 1)?;
         let first_wday = from_time.weekday().num_days_from_monday() as i64 + 1;
@@ -220,6 +356,42 @@ pub(crate) fn offset_range_year_month_wday(
     Ok(from_time)
 }
 
+/// Move datetime into nth weekday of specified year, or of specified
+/// month in the year, counting from the end with Change::Last
+pub(crate) fn offset_nth_wday(
+    from_time: DateTime<FixedOffset>,
+    year: i64,
+    month: Option<i64>,
+    wday: i64,
+    nth: i64,
+    change: Change,
+) -> Result<DateTime<FixedOffset>, ParseError> {
+    if nth.lt(&1) {
+        return Err(ParseError::OutOfRange);
+    }
+
+    let from_end = change.eq(&Change::Last);
+
+    let range_month = match from_end {
+        true => month.unwrap_or(12),
+        false => month.unwrap_or(1),
+    };
+
+    let range_time = offset_range_year_month_wday(from_time, year, range_month, wday, change)?;
+
+    let new_time = match from_end {
+        true => range_time - Duration::weeks(nth - 1),
+        false => range_time + Duration::weeks(nth - 1),
+    };
+
+    let is_same_month = month.is_none() || new_time.month().eq(&range_time.month());
+
+    match new_time.year().eq(&range_time.year()) && is_same_month {
+        true => Ok(new_time),
+        false => Err(ParseError::InvalidDate),
+    }
+}
+
 /// Move datetime into previous or upcoming weekday
 pub(crate) fn offset_weekday(
     from_time: DateTime<FixedOffset>,
@@ -249,6 +421,85 @@ pub(crate) fn offset_weeks(from_time: DateTime<FixedOffset>, amount: i64, start_
     from_time - Duration::days(days_since_start) + Duration::weeks(amount)
 }
 
+/// Move datetime by given amount of weekends, to the start of the weekend, where
+/// current weekend is the ongoing one, or the upcoming one during weekdays
+pub(crate) fn offset_weekends(
+    from_time: DateTime<FixedOffset>,
+    amount: i64,
+    start_day: i64,
+    length: i64,
+) -> DateTime<FixedOffset> {
+    let curr_weekday: i64 = from_time.weekday().num_days_from_monday() as i64 + 1;
+    let days_since_start: i64 = (curr_weekday - start_day).rem_euclid(7);
+
+    let days_to_start: i64 = match days_since_start.lt(&length) {
+        true => 0 - days_since_start,
+        false => 7 - days_since_start,
+    };
+
+    from_time + Duration::days(days_to_start) + Duration::weeks(amount)
+}
+
+/// Move datetime by given amount of business days, skipping over days
+/// that are not business days, e.g. weekends and holidays
+pub(crate) fn offset_business_days(
+    from_time: DateTime<FixedOffset>,
+    amount: i64,
+    is_business_day: impl Fn(NaiveDate) -> bool,
+) -> Result<DateTime<FixedOffset>, ParseError> {
+    if amount.abs().gt(&MAX_BUSINESS_DAYS) {
+        return Err(ParseError::OutOfRange);
+    }
+
+    let step = Duration::days(amount.signum());
+    let mut new_time = from_time;
+    let mut remaining = amount.abs();
+    let mut skipped = 0;
+
+    while remaining.gt(&0) {
+        new_time = new_time.checked_add_signed(step).ok_or(ParseError::OutOfRange)?;
+
+        match is_business_day(new_time.date_naive()) {
+            true => {
+                remaining -= 1;
+                skipped = 0;
+            }
+            false => skipped += 1,
+        }
+
+        if skipped.gt(&MAX_NON_BUSINESS_DAYS) {
+            return Err(ParseError::InvalidDate);
+        }
+    }
+
+    Ok(new_time)
+}
+
+/// Move datetime into nearest business day, forwards for the first and
+/// backwards for the last business day, keeping days that are business days
+pub(crate) fn into_business_day(
+    from_time: DateTime<FixedOffset>,
+    change: Change,
+    is_business_day: impl Fn(NaiveDate) -> bool,
+) -> Result<DateTime<FixedOffset>, ParseError> {
+    let step = match change {
+        Change::Last => Duration::days(-1),
+        _ => Duration::days(1),
+    };
+
+    let mut new_time = from_time;
+
+    for _ in 0..=MAX_NON_BUSINESS_DAYS {
+        if is_business_day(new_time.date_naive()) {
+            return Ok(new_time);
+        }
+
+        new_time = new_time.checked_add_signed(step).ok_or(ParseError::OutOfRange)?;
+    }
+
+    Err(ParseError::InvalidDate)
+}
+
 /// Move datetime by given amount of years
 pub(crate) fn offset_years(from_time: DateTime<FixedOffset>, amount: i64) -> DateTime<FixedOffset> {
     let new_year: i32 = from_time.year() + amount as i32;
@@ -266,6 +517,69 @@ pub(crate) fn offset_years(from_time: DateTime<FixedOffset>, amount: i64) -> Dat
         .unwrap()
 }
 
+/// Move datetime into given UTC offset, keeping it the same moment in time
+pub(crate) fn time_offset(
+    from_time: DateTime<FixedOffset>,
+    offset_sec: i64,
+) -> Result<DateTime<FixedOffset>, ParseError> {
+    let Some(offset) = FixedOffset::east_opt(offset_sec as i32) else {
+        return Err(ParseError::OutOfRange);
+    };
+
+    Ok(from_time.with_timezone(&offset))
+}
+
+/// Resolve local time in time zone, with rules for when the local time
+/// either occurs twice or not at all due to clock changes
+#[cfg(feature = "chrono-tz")]
+pub(crate) fn local_time_tz(
+    local_time: NaiveDateTime,
+    time_zone: Tz,
+    ambiguous: AmbiguousTime,
+    skipped: SkippedTime,
+) -> Result<DateTime<FixedOffset>, ParseError> {
+    match time_zone.from_local_datetime(&local_time) {
+        LocalResult::Single(v) => Ok(v.fixed_offset()),
+        LocalResult::Ambiguous(earliest, latest) => match ambiguous {
+            AmbiguousTime::Earliest => Ok(earliest.fixed_offset()),
+            AmbiguousTime::Latest => Ok(latest.fixed_offset()),
+            AmbiguousTime::Reject => Err(ParseError::InvalidDate),
+        },
+        LocalResult::None => {
+            // Local time falls into a gap, which we either skip over using the
+            // offset from before the gap, or move back using the one after it
+            let offset_before = time_zone.offset_from_utc_datetime(&(local_time - Duration::days(1))).fix();
+            let offset_after = time_zone.offset_from_utc_datetime(&(local_time + Duration::days(1))).fix();
+
+            let utc_time = match skipped {
+                SkippedTime::Forward => local_time - offset_before,
+                SkippedTime::Backward => local_time - offset_after,
+                SkippedTime::Reject => return Err(ParseError::InvalidDate),
+            };
+
+            Ok(time_zone.from_utc_datetime(&utc_time).fixed_offset())
+        }
+    }
+}
+
+/// Move datetime into named time zone, from its position in the time zone database,
+/// keeping it the same moment in time, and get the time zone to resolve local times
+/// in from there on
+#[cfg_attr(not(feature = "chrono-tz"), allow(unused_variables))]
+pub(crate) fn time_zone(
+    from_time: DateTime<FixedOffset>,
+    zone_index: usize,
+    local_zone: LocalZone,
+) -> Result<(DateTime<FixedOffset>, LocalZone), ParseError> {
+    #[cfg(feature = "chrono-tz")]
+    if let Some(time_zone) = chrono_tz::TZ_VARIANTS.get(zone_index) {
+        let new_zone = LocalZone { time_zone: Some(*time_zone), ..local_zone };
+        return Ok((new_zone.resolve_instant(from_time), new_zone));
+    }
+
+    Err(ParseError::OutOfRange)
+}
+
 // Move datetime into specified 12-hour, minute and second
 pub(crate) fn time_12h(
     from_time: DateTime<FixedOffset>,
@@ -273,9 +587,9 @@ pub(crate) fn time_12h(
     min: i64,
     sec: i64,
     meridiem: i64,
-) -> Result<DateTime<FixedOffset>, ()> {
+) -> Result<DateTime<FixedOffset>, ParseError> {
     if hour.lt(&1) || hour.gt(&12) {
-        return Err(());
+        return Err(ParseError::OutOfRange);
     }
 
     let hour = match hour.eq(&12) {
@@ -305,13 +619,13 @@ pub(crate) fn time_hms(
     min: i64,
     sec: i64,
     ms: i64,
-) -> Result<DateTime<FixedOffset>, ()> {
+) -> Result<DateTime<FixedOffset>, ParseError> {
     if hour.lt(&0) || min.lt(&0) || sec.lt(&0) || ms.lt(&0) {
-        return Err(());
+        return Err(ParseError::OutOfRange);
     }
 
     if hour.gt(&23) || min.gt(&59) || sec.gt(&59) || ms.gt(&999) {
-        return Err(());
+        return Err(ParseError::OutOfRange);
     }
 
     Ok(from_time
@@ -378,8 +692,73 @@ mod tests {
         assert_eq!(date_ymd(from_time, 2022, 2, 25).unwrap().to_string(), "2022-02-25 15:22:28 +02:00",);
         assert_eq!(date_ymd(from_time, 2024, 2, 29).unwrap().to_string(), "2024-02-29 15:22:28 +02:00",);
 
-        assert!(date_ymd(from_time, 2024, 13, 10).is_err());
-        assert!(date_ymd(from_time, 2024, 2, 30).is_err());
+        assert_eq!(date_ymd(from_time, 2024, 13, 10), Err(ParseError::OutOfRange));
+        assert_eq!(date_ymd(from_time, 2024, 2, 32), Err(ParseError::OutOfRange));
+        assert_eq!(date_ymd(from_time, 2024, 2, 30), Err(ParseError::InvalidDate));
+    }
+
+    #[test]
+    fn test_full_year() {
+        assert_eq!(full_year(2026, 23, TwoDigitYear::Pivot(50)), 2023);
+        assert_eq!(full_year(2026, 49, TwoDigitYear::Pivot(50)), 2049);
+        assert_eq!(full_year(2026, 50, TwoDigitYear::Pivot(50)), 1950);
+        assert_eq!(full_year(2026, 99, TwoDigitYear::Pivot(0)), 1999);
+        assert_eq!(full_year(2026, 0, TwoDigitYear::Pivot(100)), 2000);
+
+        assert_eq!(full_year(2026, 23, TwoDigitYear::Closest), 2023);
+        assert_eq!(full_year(2026, 75, TwoDigitYear::Closest), 2075);
+        assert_eq!(full_year(2026, 77, TwoDigitYear::Closest), 1977);
+        assert_eq!(full_year(2026, 76, TwoDigitYear::Closest), 1976);
+        assert_eq!(full_year(1995, 5, TwoDigitYear::Closest), 2005);
+    }
+
+    #[test]
+    fn test_date_yq() {
+        let from_time = into_datetime("2022-01-31T15:22:28+02:00");
+
+        assert_eq!(date_yq(from_time, 2024, 1).unwrap().to_string(), "2024-01-01 15:22:28 +02:00");
+        assert_eq!(date_yq(from_time, 2024, 4).unwrap().to_string(), "2024-10-01 15:22:28 +02:00");
+
+        assert_eq!(date_yq(from_time, 2024, 0), Err(ParseError::OutOfRange));
+        assert_eq!(date_yq(from_time, 2024, 5), Err(ParseError::OutOfRange));
+
+        assert_eq!(into_quarter(1), 1);
+        assert_eq!(into_quarter(3), 1);
+        assert_eq!(into_quarter(4), 2);
+        assert_eq!(into_quarter(12), 4);
+    }
+
+    #[test]
+    fn test_fiscal_year() {
+        let from_time = into_datetime("2022-01-31T15:22:28+02:00");
+
+        assert_eq!(fiscal_year_month(2025, 1, 0), (2025, 1));
+        assert_eq!(fiscal_year_month(2025, 7, 0), (2024, 7));
+        assert_eq!(fiscal_year_month(2025, 7, 6), (2025, 1));
+        assert_eq!(fiscal_year_month(2025, 7, 11), (2025, 6));
+        assert_eq!(fiscal_year_month(2025, 7, -3), (2024, 4));
+
+        assert_eq!(into_fiscal_quarter(2024, 5, 1), (2024, 2));
+        assert_eq!(into_fiscal_quarter(2024, 6, 7), (2024, 4));
+        assert_eq!(into_fiscal_quarter(2024, 7, 7), (2025, 1));
+        assert_eq!(into_fiscal_quarter(2024, 12, 10), (2025, 1));
+
+        assert_eq!(
+            offset_range_fiscal(from_time, 2025, 7, 0..12, Change::First)
+                .unwrap()
+                .to_string(),
+            "2024-07-01 15:22:28 +02:00"
+        );
+        assert_eq!(
+            offset_range_fiscal(from_time, 2025, 7, 0..12, Change::Last)
+                .unwrap()
+                .to_string(),
+            "2025-06-30 15:22:28 +02:00"
+        );
+        assert_eq!(
+            offset_range_fiscal(from_time, 2025, 7, 6..9, Change::Last).unwrap().to_string(),
+            "2025-03-31 15:22:28 +02:00"
+        );
     }
 
     #[test]
@@ -391,8 +770,9 @@ mod tests {
         assert_eq!(date_yw(from_time, 2025, 1, 1).unwrap().to_string(), "2024-12-30 15:22:28 +02:00",);
         assert_eq!(date_yw(from_time, 2025, 1, 7).unwrap().to_string(), "2024-12-29 15:22:28 +02:00",);
 
-        assert!(date_yw(from_time, 2020, 0, 1).is_err());
-        assert!(date_yw(from_time, 2020, 54, 1).is_err());
+        assert_eq!(date_yw(from_time, 2020, 0, 1), Err(ParseError::OutOfRange));
+        assert_eq!(date_yw(from_time, 2020, 54, 1), Err(ParseError::OutOfRange));
+        assert_eq!(date_yw(from_time, 2025, 53, 1), Err(ParseError::InvalidDate));
     }
 
     #[test]
@@ -518,6 +898,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_offset_nth_wdays() {
+        let monday = 1;
+        let from_time = into_datetime("2024-01-31T15:22:28+02:00");
+
+        let expect: Vec<(i64, Option<i64>, i64, Change, &str)> = vec![
+            (2024, Some(2), 1, Change::First, "2024-02-05 15:22:28 +02:00"),
+            (2024, Some(2), 2, Change::First, "2024-02-12 15:22:28 +02:00"),
+            (2024, Some(2), 4, Change::First, "2024-02-26 15:22:28 +02:00"),
+            (2024, Some(2), 1, Change::Last, "2024-02-26 15:22:28 +02:00"),
+            (2024, Some(2), 2, Change::Last, "2024-02-19 15:22:28 +02:00"),
+            (2024, Some(4), 5, Change::First, "2024-04-29 15:22:28 +02:00"),
+            (2024, None, 10, Change::First, "2024-03-04 15:22:28 +02:00"),
+            (2024, None, 53, Change::First, "2024-12-30 15:22:28 +02:00"),
+            (2024, None, 2, Change::Last, "2024-12-23 15:22:28 +02:00"),
+        ];
+
+        for (new_year, new_month, nth, change, expect_time) in expect {
+            let result_time = offset_nth_wday(from_time, new_year, new_month, monday, nth, change);
+            assert_eq!(result_time.unwrap().to_string(), expect_time);
+        }
+
+        assert_eq!(offset_nth_wday(from_time, 2024, Some(2), monday, 5, Change::First), Err(ParseError::InvalidDate));
+        assert_eq!(offset_nth_wday(from_time, 2024, Some(2), monday, 5, Change::Last), Err(ParseError::InvalidDate));
+        assert_eq!(offset_nth_wday(from_time, 2024, None, monday, 54, Change::First), Err(ParseError::InvalidDate));
+        assert_eq!(offset_nth_wday(from_time, 2024, Some(2), monday, 0, Change::First), Err(ParseError::OutOfRange));
+    }
+
     #[test]
     fn test_offset_weekdays() {
         let expect: Vec<(&str, i64, Change, &str)> = vec![
@@ -573,6 +981,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_offset_weekends() {
+        let expect: Vec<(&str, i64, i64, i64, &str)> = vec![
+            // Saturday and Sunday as weekend
+            ("2024-05-08T15:22:28+02:00", 0, 6, 2, "2024-05-11 15:22:28 +02:00"),
+            ("2024-05-11T15:22:28+02:00", 0, 6, 2, "2024-05-11 15:22:28 +02:00"),
+            ("2024-05-12T15:22:28+02:00", 0, 6, 2, "2024-05-11 15:22:28 +02:00"),
+            ("2024-05-13T15:22:28+02:00", 0, 6, 2, "2024-05-18 15:22:28 +02:00"),
+            ("2024-05-08T15:22:28+02:00", -1, 6, 2, "2024-05-04 15:22:28 +02:00"),
+            ("2024-05-12T15:22:28+02:00", -1, 6, 2, "2024-05-04 15:22:28 +02:00"),
+            ("2024-05-08T15:22:28+02:00", 1, 6, 2, "2024-05-18 15:22:28 +02:00"),
+            // Friday and Saturday as weekend
+            ("2024-05-08T15:22:28+02:00", 0, 5, 2, "2024-05-10 15:22:28 +02:00"),
+            ("2024-05-11T15:22:28+02:00", 0, 5, 2, "2024-05-10 15:22:28 +02:00"),
+            ("2024-05-12T15:22:28+02:00", 0, 5, 2, "2024-05-17 15:22:28 +02:00"),
+            // Sunday only as weekend
+            ("2024-05-11T15:22:28+02:00", 0, 7, 1, "2024-05-12 15:22:28 +02:00"),
+            ("2024-05-12T15:22:28+02:00", 1, 7, 1, "2024-05-19 15:22:28 +02:00"),
+        ];
+
+        for (from_time, move_weekends, start_day, length, expect_time) in expect {
+            let result_time = offset_weekends(into_datetime(from_time), move_weekends, start_day, length);
+            assert_eq!(result_time.to_string(), expect_time, "{}", from_time);
+        }
+    }
+
+    #[test]
+    fn test_offset_business_days() {
+        let holiday = NaiveDate::from_ymd_opt(2024, 5, 13).unwrap();
+        let is_business_day = |v: NaiveDate| v.weekday().num_days_from_monday().lt(&5) && v.ne(&holiday);
+
+        let expect: Vec<(&str, i64, &str)> = vec![
+            ("2024-05-08T15:22:28+02:00", 0, "2024-05-08 15:22:28 +02:00"),
+            ("2024-05-08T15:22:28+02:00", 1, "2024-05-09 15:22:28 +02:00"),
+            ("2024-05-08T15:22:28+02:00", 3, "2024-05-14 15:22:28 +02:00"),
+            ("2024-05-11T15:22:28+02:00", 1, "2024-05-14 15:22:28 +02:00"),
+            ("2024-05-14T15:22:28+02:00", -1, "2024-05-10 15:22:28 +02:00"),
+            ("2024-05-14T15:22:28+02:00", -5, "2024-05-06 15:22:28 +02:00"),
+        ];
+
+        for (from_time, move_days, expect_time) in expect {
+            let result_time = offset_business_days(into_datetime(from_time), move_days, is_business_day);
+            assert_eq!(result_time.unwrap().to_string(), expect_time, "{}", from_time);
+        }
+
+        let result_time = offset_business_days(into_datetime("2024-05-08T15:22:28+02:00"), 1, |_| false);
+        assert_eq!(result_time, Err(ParseError::InvalidDate));
+    }
+
+    #[test]
+    fn test_into_business_day() {
+        let holiday = NaiveDate::from_ymd_opt(2024, 5, 13).unwrap();
+        let is_business_day = |v: NaiveDate| v.weekday().num_days_from_monday().lt(&5) && v.ne(&holiday);
+
+        let expect: Vec<(&str, Change, &str)> = vec![
+            ("2024-05-08T15:22:28+02:00", Change::First, "2024-05-08 15:22:28 +02:00"),
+            ("2024-05-08T15:22:28+02:00", Change::Last, "2024-05-08 15:22:28 +02:00"),
+            ("2024-05-11T15:22:28+02:00", Change::First, "2024-05-14 15:22:28 +02:00"),
+            ("2024-05-13T15:22:28+02:00", Change::Last, "2024-05-10 15:22:28 +02:00"),
+        ];
+
+        for (from_time, change, expect_time) in expect {
+            let result_time = into_business_day(into_datetime(from_time), change, is_business_day);
+            assert_eq!(result_time.unwrap().to_string(), expect_time, "{}", from_time);
+        }
+
+        let result_time = into_business_day(into_datetime("2024-05-08T15:22:28+02:00"), Change::First, |_| false);
+        assert_eq!(result_time, Err(ParseError::InvalidDate));
+    }
+
     #[test]
     fn test_offset_years() {
         let expect: Vec<(&str, i64, &str)> = vec![
@@ -606,6 +1084,63 @@ mod tests {
         assert!(time_hms(from_time, 0, 0, 60, 0).is_err());
     }
 
+    #[test]
+    fn test_time_offset() {
+        let from_time = into_datetime("2022-02-28T23:22:28+02:00");
+
+        assert_eq!(time_offset(from_time, 0).unwrap().to_string(), "2022-02-28 21:22:28 +00:00");
+        assert_eq!(time_offset(from_time, 7200).unwrap().to_string(), "2022-02-28 23:22:28 +02:00");
+        assert_eq!(time_offset(from_time, 10800).unwrap().to_string(), "2022-03-01 00:22:28 +03:00");
+        assert_eq!(time_offset(from_time, -25200).unwrap().to_string(), "2022-02-28 14:22:28 -07:00");
+
+        assert!(time_offset(from_time, 86400).is_err());
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_local_time_tz() {
+        let helsinki = chrono_tz::Europe::Helsinki;
+        let naive_time = |v: &str| NaiveDateTime::parse_from_str(v, "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let expect: Vec<(&str, AmbiguousTime, SkippedTime, &str)> = vec![
+            ("2024-01-12 15:22:28", AmbiguousTime::Reject, SkippedTime::Reject, "2024-01-12 15:22:28 +02:00"),
+            ("2024-07-12 15:22:28", AmbiguousTime::Reject, SkippedTime::Reject, "2024-07-12 15:22:28 +03:00"),
+            // Clocks turned back from 04:00 to 03:00
+            ("2024-10-27 03:30:00", AmbiguousTime::Earliest, SkippedTime::Reject, "2024-10-27 03:30:00 +03:00"),
+            ("2024-10-27 03:30:00", AmbiguousTime::Latest, SkippedTime::Reject, "2024-10-27 03:30:00 +02:00"),
+            // Clocks turned forward from 03:00 to 04:00
+            ("2024-03-31 03:30:00", AmbiguousTime::Reject, SkippedTime::Forward, "2024-03-31 04:30:00 +03:00"),
+            ("2024-03-31 03:30:00", AmbiguousTime::Reject, SkippedTime::Backward, "2024-03-31 02:30:00 +02:00"),
+        ];
+
+        for (local_time, ambiguous, skipped, expect_time) in expect {
+            let result_time = local_time_tz(naive_time(local_time), helsinki, ambiguous, skipped);
+            assert_eq!(result_time.unwrap().to_string(), expect_time);
+        }
+
+        let ambiguous_time = naive_time("2024-10-27 03:30:00");
+        assert!(local_time_tz(ambiguous_time, helsinki, AmbiguousTime::Reject, SkippedTime::Forward).is_err());
+
+        let skipped_time = naive_time("2024-03-31 03:30:00");
+        assert!(local_time_tz(skipped_time, helsinki, AmbiguousTime::Earliest, SkippedTime::Reject).is_err());
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_time_zone() {
+        let from_time = into_datetime("2022-02-28T23:22:28+02:00");
+        let new_york = chrono_tz::TZ_VARIANTS
+            .iter()
+            .position(|v| v.eq(&chrono_tz::America::New_York))
+            .unwrap();
+
+        let (result_time, result_zone) = time_zone(from_time, new_york, LocalZone::default()).unwrap();
+        assert_eq!(result_time.to_string(), "2022-02-28 16:22:28 -05:00");
+        assert_eq!(result_zone.time_zone, Some(chrono_tz::America::New_York));
+
+        assert!(time_zone(from_time, chrono_tz::TZ_VARIANTS.len(), LocalZone::default()).is_err());
+    }
+
     #[test]
     fn test_time_12h() {
         let from_time = into_datetime("2022-02-28T15:22:28+02:00");
@@ -0,0 +1,35 @@
+use std::fmt;
+use std::ops::Range;
+
+/// Reason for failing to convert a source string
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Word that is neither a token nor a part of any pattern,
+    /// with its byte range in the source string
+    UnknownToken { span: Range<usize> },
+    /// Source string does not match any supported pattern
+    NoMatchingPattern,
+    /// Date or time does not exist, e.g. "Feb 30th"
+    InvalidDate,
+    /// Weekday does not match the date, e.g. "Sun April 1 2023"
+    WeekdayMismatch,
+    /// Value is outside of its allowed range, e.g. "25:00"
+    OutOfRange,
+    /// Unit can't be used in the given context, e.g. months in seconds
+    UnsupportedUnit { unit: &'static str },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnknownToken { span } => write!(f, "unknown token at {}..{}", span.start, span.end),
+            ParseError::NoMatchingPattern => write!(f, "no matching pattern"),
+            ParseError::InvalidDate => write!(f, "date or time does not exist"),
+            ParseError::WeekdayMismatch => write!(f, "weekday does not match the date"),
+            ParseError::OutOfRange => write!(f, "value out of range"),
+            ParseError::UnsupportedUnit { unit } => write!(f, "unsupported unit {}", unit),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -1,19 +1,76 @@
+use crate::calendar::HolidayCalendar;
 use crate::convert;
-use crate::convert::Change;
+use crate::convert::{Change, LocalZone};
+use crate::error::ParseError;
+use crate::holiday::HolidayRules;
 use crate::pattern::Pattern;
-use crate::token::{Token, UnitNames};
-use chrono::{DateTime, Datelike, Duration, FixedOffset};
+use crate::token;
+use crate::token::{
+    DECIMAL_SCALE, DateOrder, DayPeriod, PhraseNames, Rounding, SignStyle, Token, TokenType, TwoDigitYear, UnitNames,
+    WeekendDays,
+};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveTime};
 use std::cmp;
 use std::cmp::{Ordering, PartialEq};
 use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::sync::Arc;
+
+const HOLIDAY_SEARCH_YEARS: i64 = 8;
+
+// Patterns that accept a decimal amount of a unit, e.g. "1.5 hours"
+const DECIMAL_PATTERNS: [&Pattern; 7] = [
+    &Pattern::MinusUnit,
+    &Pattern::PlusUnit,
+    &Pattern::UnitAgo,
+    &Pattern::HalfUnit,
+    &Pattern::HalfUnitAgo,
+    &Pattern::UnitAndHalf,
+    &Pattern::UnitAndHalfAgo,
+];
+
+// Units of duration strings, from weeks to microseconds, in microseconds
+const DURATION_UNITS: [(i8, i64); 7] = [
+    (5, 604_800_000_000),
+    (4, 86_400_000_000),
+    (3, 3_600_000_000),
+    (2, 60_000_000),
+    (1, 1_000_000),
+    (0, 1_000),
+    (-1, 1),
+];
 
-const FUZZY_PATTERNS: [(&Pattern, fn(FuzzyDate, &CallValues, &Rules) -> Result<FuzzyDate, ()>); 69] = [
+// Units of compact durations, e.g. "1h30m", in nanoseconds
+const COMPACT_UNITS: [(&'static str, i128); 13] = [
+    ("ns", 1),
+    ("us", 1_000),
+    ("µs", 1_000),
+    ("μs", 1_000),
+    ("ms", 1_000_000),
+    ("s", 1_000_000_000),
+    ("sec", 1_000_000_000),
+    ("m", 60_000_000_000),
+    ("min", 60_000_000_000),
+    ("h", 3_600_000_000_000),
+    ("hr", 3_600_000_000_000),
+    ("d", 86_400_000_000_000),
+    ("w", 604_800_000_000_000),
+];
+
+const FUZZY_PATTERNS: [(&Pattern, fn(FuzzyDate, &CallValues, &Rules) -> Result<FuzzyDate, ParseError>); 127] = [
     // KEYWORDS
     (&Pattern::Now, |c, _, _| Ok(c)),
     (&Pattern::Today, |c, _, r| c.rule_time_reset(r)),
     (&Pattern::Midnight, |c, _, _| c.time_hms(0, 0, 0, 0)),
     (&Pattern::Yesterday, |c, _, r| c.offset_unit_keyword(TimeUnit::Days, -1, r)?.rule_time_reset(r)),
     (&Pattern::Tomorrow, |c, _, r| c.offset_unit_keyword(TimeUnit::Days, 1, r)?.rule_time_reset(r)),
+    // NAMED TIMES OF DAY
+    (&Pattern::Morning, |c, _, r| c.time_period(DayPeriod::Morning, r)),
+    (&Pattern::Noon, |c, _, r| c.time_period(DayPeriod::Noon, r)),
+    (&Pattern::Afternoon, |c, _, r| c.time_period(DayPeriod::Afternoon, r)),
+    (&Pattern::Evening, |c, _, r| c.time_period(DayPeriod::Evening, r)),
+    (&Pattern::Tonight, |c, _, r| c.time_period(DayPeriod::Night, r)),
+    (&Pattern::EndOfDay, |c, _, r| c.time_period(DayPeriod::EndOfDay, r)),
     // WEEKDAY OFFSETS
     (&Pattern::Wday, |c, v, r| c.offset_current_weekday(v.get_int(0))?.rule_time_reset(r)),
     (&Pattern::ThisWday, |c, v, r| c.offset_weekday(v.get_int(0), Change::None)?.rule_time_reset(r)),
@@ -23,16 +80,54 @@ const FUZZY_PATTERNS: [(&Pattern, fn(FuzzyDate, &CallValues, &Rules) -> Result<F
     (&Pattern::ThisMonth, |c, v, r| c.offset_month(v.get_int(0), Change::None)?.rule_time_reset(r)),
     (&Pattern::PrevMonth, |c, v, r| c.offset_month(v.get_int(0), Change::Prev)?.rule_time_reset(r)),
     (&Pattern::NextMonth, |c, v, r| c.offset_month(v.get_int(0), Change::Next)?.rule_time_reset(r)),
+    // HOLIDAYS
+    (&Pattern::Holiday, |c, v, r| c.date_holiday(c.rule_year(), v.get_int(0), r)?.rule_time_reset(r)),
+    (&Pattern::HolidayYear, |c, v, r| {
+        c.rule_allow_year_dates(r)?
+            .date_holiday(v.get_int(1), v.get_int(0), r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::PrevHoliday, |c, v, r| c.offset_holiday(v.get_int(0), Change::Prev, r)?.rule_time_reset(r)),
+    (&Pattern::NextHoliday, |c, v, r| c.offset_holiday(v.get_int(0), Change::Next, r)?.rule_time_reset(r)),
+    (&Pattern::UnitBeforeHoliday, |c, v, r| {
+        c.date_holiday(c.rule_year(), v.get_int(2), r)?
+            .rule_time_reset(r)?
+            .offset_unit_exact(v.get_unit(1), 0 - v.get_whole(0)?, r)
+    }),
+    (&Pattern::UnitBeforeHolidayYear, |c, v, r| {
+        c.rule_allow_year_dates(r)?
+            .date_holiday(v.get_int(3), v.get_int(2), r)?
+            .rule_time_reset(r)?
+            .offset_unit_exact(v.get_unit(1), 0 - v.get_whole(0)?, r)
+    }),
+    (&Pattern::UnitAfterHoliday, |c, v, r| {
+        c.date_holiday(c.rule_year(), v.get_int(2), r)?
+            .rule_time_reset(r)?
+            .offset_unit_exact(v.get_unit(1), v.get_whole(0)?, r)
+    }),
+    (&Pattern::UnitAfterHolidayYear, |c, v, r| {
+        c.rule_allow_year_dates(r)?
+            .date_holiday(v.get_int(3), v.get_int(2), r)?
+            .rule_time_reset(r)?
+            .offset_unit_exact(v.get_unit(1), v.get_whole(0)?, r)
+    }),
     // KEYWORD OFFSETS
     (&Pattern::ThisUnit, |c, v, r| c.offset_unit_keyword(v.get_unit(0), 0, r)),
     (&Pattern::PastUnit, |c, v, r| c.offset_unit_exact(v.get_unit(0), -1, r)),
     (&Pattern::PrevUnit, |c, v, r| c.offset_unit_keyword(v.get_unit(0), -1, r)),
-    (&Pattern::PrevNUnit, |c, v, r| c.offset_unit_keyword(v.get_unit(1), 0 - v.get_int(0), r)),
+    (&Pattern::PrevNUnit, |c, v, r| c.offset_unit_keyword(v.get_unit(1), 0 - v.get_whole(0)?, r)),
     (&Pattern::NextUnit, |c, v, r| c.offset_unit_keyword(v.get_unit(0), 1, r)),
     // NUMERIC OFFSETS
-    (&Pattern::MinusUnit, |c, v, r| c.offset_unit_exact(v.get_unit(1), 0 - v.get_int(0), r)),
-    (&Pattern::PlusUnit, |c, v, r| c.offset_unit_exact(v.get_unit(1), v.get_int(0), r)),
-    (&Pattern::UnitAgo, |c, v, r| c.offset_unit_exact(v.get_unit(1), 0 - v.get_int(0), r)),
+    (&Pattern::MinusUnit, |c, v, r| c.offset_unit_decimal(v.get_unit(1), 0 - v.get_decimal(0), r)),
+    (&Pattern::PlusUnit, |c, v, r| c.offset_unit_decimal(v.get_unit(1), v.get_decimal(0), r)),
+    (&Pattern::UnitAgo, |c, v, r| c.offset_unit_decimal(v.get_unit(1), 0 - v.get_decimal(0), r)),
+    // HALF OFFSETS
+    (&Pattern::HalfUnit, |c, v, r| c.offset_unit_decimal(v.get_unit(1), v.get_half(0)?, r)),
+    (&Pattern::HalfUnitAgo, |c, v, r| c.offset_unit_decimal(v.get_unit(1), 0 - v.get_half(0)?, r)),
+    (&Pattern::UnitAndHalf, |c, v, r| c.offset_unit_decimal(v.get_unit(1), v.get_decimal(0) + v.get_half(2)?, r)),
+    (&Pattern::UnitAndHalfAgo, |c, v, r| {
+        c.offset_unit_decimal(v.get_unit(1), 0 - v.get_decimal(0) - v.get_half(2)?, r)
+    }),
     // EXACT UNIT
     (&Pattern::UnitInt, |c, v, r| {
         c.ensure_unit(v.get_unit(0), TimeUnit::Weeks)?
@@ -80,31 +175,68 @@ const FUZZY_PATTERNS: [(&Pattern, fn(FuzzyDate, &CallValues, &Rules) -> Result<F
             .rule_time_reset(r)
     }),
     (&Pattern::FirstUnitOfThisUnit, |c, v, r| {
-        c.offset_range_unit(v.get_unit(0), v.get_unit(1), Change::First)?
+        c.offset_range_unit(v.get_unit(0), v.get_unit(1), Change::First, r)?
             .rule_time_reset(r)
     }),
     (&Pattern::LastUnitOfThisUnit, |c, v, r| {
-        c.offset_range_unit(v.get_unit(0), v.get_unit(1), Change::Last)?
+        c.offset_range_unit(v.get_unit(0), v.get_unit(1), Change::Last, r)?
             .rule_time_reset(r)
     }),
     (&Pattern::FirstUnitOfPrevUnit, |c, v, r| {
         c.offset_unit_keyword(v.get_unit(1), -1, r)?
-            .offset_range_unit(v.get_unit(0), v.get_unit(1), Change::First)?
+            .offset_range_unit(v.get_unit(0), v.get_unit(1), Change::First, r)?
             .rule_time_reset(r)
     }),
     (&Pattern::LastUnitOfPrevUnit, |c, v, r| {
         c.offset_unit_keyword(v.get_unit(1), -1, r)?
-            .offset_range_unit(v.get_unit(0), v.get_unit(1), Change::Last)?
+            .offset_range_unit(v.get_unit(0), v.get_unit(1), Change::Last, r)?
             .rule_time_reset(r)
     }),
     (&Pattern::FirstUnitOfNextUnit, |c, v, r| {
         c.offset_unit_keyword(v.get_unit(1), 1, r)?
-            .offset_range_unit(v.get_unit(0), v.get_unit(1), Change::First)?
+            .offset_range_unit(v.get_unit(0), v.get_unit(1), Change::First, r)?
             .rule_time_reset(r)
     }),
     (&Pattern::LastUnitOfNextUnit, |c, v, r| {
         c.offset_unit_keyword(v.get_unit(1), 1, r)?
-            .offset_range_unit(v.get_unit(0), v.get_unit(1), Change::Last)?
+            .offset_range_unit(v.get_unit(0), v.get_unit(1), Change::Last, r)?
+            .rule_time_reset(r)
+    }),
+    // FISCAL OFFSETS
+    (&Pattern::ThisFiscalUnit, |c, v, r| {
+        c.offset_range_fiscal_unit(TimeUnit::Days, v.get_unit(0), 0, Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::PrevFiscalUnit, |c, v, r| {
+        c.offset_range_fiscal_unit(TimeUnit::Days, v.get_unit(0), -1, Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::NextFiscalUnit, |c, v, r| {
+        c.offset_range_fiscal_unit(TimeUnit::Days, v.get_unit(0), 1, Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::FirstUnitOfThisFiscalUnit, |c, v, r| {
+        c.offset_range_fiscal_unit(v.get_unit(0), v.get_unit(1), 0, Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::LastUnitOfThisFiscalUnit, |c, v, r| {
+        c.offset_range_fiscal_unit(v.get_unit(0), v.get_unit(1), 0, Change::Last, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::FirstUnitOfPrevFiscalUnit, |c, v, r| {
+        c.offset_range_fiscal_unit(v.get_unit(0), v.get_unit(1), -1, Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::LastUnitOfPrevFiscalUnit, |c, v, r| {
+        c.offset_range_fiscal_unit(v.get_unit(0), v.get_unit(1), -1, Change::Last, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::FirstUnitOfNextFiscalUnit, |c, v, r| {
+        c.offset_range_fiscal_unit(v.get_unit(0), v.get_unit(1), 1, Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::LastUnitOfNextFiscalUnit, |c, v, r| {
+        c.offset_range_fiscal_unit(v.get_unit(0), v.get_unit(1), 1, Change::Last, r)?
             .rule_time_reset(r)
     }),
     // FIRST/LAST WEEKDAY OFFSETS
@@ -132,6 +264,79 @@ const FUZZY_PATTERNS: [(&Pattern, fn(FuzzyDate, &CallValues, &Rules) -> Result<F
         c.offset_range_year_month_wday(v.get_int(1), 12, v.get_int(0), Change::Last)?
             .rule_time_reset(r)
     }),
+    (&Pattern::FirstWdayOfThisUnit, |c, v, r| {
+        c.offset_nth_wday_unit(v.get_unit(1), 0, v.get_int(0), 1, Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::FirstWdayOfPrevUnit, |c, v, r| {
+        c.offset_nth_wday_unit(v.get_unit(1), -1, v.get_int(0), 1, Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::FirstWdayOfNextUnit, |c, v, r| {
+        c.offset_nth_wday_unit(v.get_unit(1), 1, v.get_int(0), 1, Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::LastWdayOfThisUnit, |c, v, r| {
+        c.offset_nth_wday_unit(v.get_unit(1), 0, v.get_int(0), 1, Change::Last, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::LastWdayOfPrevUnit, |c, v, r| {
+        c.offset_nth_wday_unit(v.get_unit(1), -1, v.get_int(0), 1, Change::Last, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::LastWdayOfNextUnit, |c, v, r| {
+        c.offset_nth_wday_unit(v.get_unit(1), 1, v.get_int(0), 1, Change::Last, r)?
+            .rule_time_reset(r)
+    }),
+    // NTH WEEKDAY OFFSETS
+    (&Pattern::NthWdayOfMonth, |c, v, r| {
+        c.offset_nth_wday(c.rule_year(), Some(v.get_int(2)), v.get_int(1), v.get_int(0), Change::First)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::NthWdayOfMonthYear, |c, v, r| {
+        c.offset_nth_wday(v.get_int(3), Some(v.get_int(2)), v.get_int(1), v.get_int(0), Change::First)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::NthWdayOfYear, |c, v, r| {
+        c.offset_nth_wday(v.get_int(2), None, v.get_int(1), v.get_int(0), Change::First)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::NthWdayOfThisUnit, |c, v, r| {
+        c.offset_nth_wday_unit(v.get_unit(2), 0, v.get_int(1), v.get_int(0), Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::NthWdayOfPrevUnit, |c, v, r| {
+        c.offset_nth_wday_unit(v.get_unit(2), -1, v.get_int(1), v.get_int(0), Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::NthWdayOfNextUnit, |c, v, r| {
+        c.offset_nth_wday_unit(v.get_unit(2), 1, v.get_int(1), v.get_int(0), Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::NthLastWdayOfMonth, |c, v, r| {
+        c.offset_nth_wday(c.rule_year(), Some(v.get_int(2)), v.get_int(1), v.get_int(0), Change::Last)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::NthLastWdayOfMonthYear, |c, v, r| {
+        c.offset_nth_wday(v.get_int(3), Some(v.get_int(2)), v.get_int(1), v.get_int(0), Change::Last)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::NthLastWdayOfYear, |c, v, r| {
+        c.offset_nth_wday(v.get_int(2), None, v.get_int(1), v.get_int(0), Change::Last)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::NthLastWdayOfThisUnit, |c, v, r| {
+        c.offset_nth_wday_unit(v.get_unit(2), 0, v.get_int(1), v.get_int(0), Change::Last, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::NthLastWdayOfPrevUnit, |c, v, r| {
+        c.offset_nth_wday_unit(v.get_unit(2), -1, v.get_int(1), v.get_int(0), Change::Last, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::NthLastWdayOfNextUnit, |c, v, r| {
+        c.offset_nth_wday_unit(v.get_unit(2), 1, v.get_int(1), v.get_int(0), Change::Last, r)?
+            .rule_time_reset(r)
+    }),
     // 20230130
     (&Pattern::Integer, |c, v, r| c.rule_allow_year_dates(r)?.date_iso8601(v.get_string(0))?.rule_time_reset(r)),
     // 2023
@@ -142,6 +347,39 @@ const FUZZY_PATTERNS: [(&Pattern, fn(FuzzyDate, &CallValues, &Rules) -> Result<F
             .date_yw(v.get_int(0), v.get_int(1), r)?
             .rule_time_reset(r)
     }),
+    // Q1, Q1 2023, 2023-Q1
+    (&Pattern::Quarter, |c, v, r| c.date_yq(c.rule_year(), v.get_int(0))?.rule_time_reset(r)),
+    (&Pattern::QuarterYear, |c, v, r| {
+        c.rule_allow_year_dates(r)?
+            .date_yq(v.get_int(1), v.get_int(0))?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::YearQuarter, |c, v, r| {
+        c.rule_allow_year_dates(r)?
+            .date_yq(v.get_int(0), v.get_int(1))?
+            .rule_time_reset(r)
+    }),
+    // FY2025, FY25, Q1 FY2025
+    (&Pattern::FiscalYear, |c, v, r| {
+        c.rule_allow_year_dates(r)?
+            .offset_range_fiscal(TimeUnit::Days, r.fiscal_year(v.get_int(0))?, 0..12, Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::QuarterFiscalYear, |c, v, r| {
+        c.rule_allow_year_dates(r)?
+            .offset_range_fiscal_quarter(r.fiscal_year(v.get_int(1))?, v.get_int(0), r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::FirstUnitOfFiscalYear, |c, v, r| {
+        c.rule_allow_year_dates(r)?
+            .offset_range_fiscal(v.get_unit(0), r.fiscal_year(v.get_int(1))?, 0..12, Change::First, r)?
+            .rule_time_reset(r)
+    }),
+    (&Pattern::LastUnitOfFiscalYear, |c, v, r| {
+        c.rule_allow_year_dates(r)?
+            .offset_range_fiscal(v.get_unit(0), r.fiscal_year(v.get_int(1))?, 0..12, Change::Last, r)?
+            .rule_time_reset(r)
+    }),
     // April, April 2023
     (&Pattern::Month, |c, v, r| c.date_ym(c.rule_year(), v.get_int(0))?.rule_time_reset(r)),
     (&Pattern::MonthYear, |c, v, r| {
@@ -152,22 +390,33 @@ const FUZZY_PATTERNS: [(&Pattern, fn(FuzzyDate, &CallValues, &Rules) -> Result<F
     // @1705072948, @1705072948.452
     (&Pattern::Timestamp, |c, v, r| c.rule_allow_year_dates(r)?.date_stamp(v.get_int(0), 0)),
     (&Pattern::TimestampFloat, |c, v, r| c.rule_allow_year_dates(r)?.date_stamp(v.get_int(0), v.get_ms(1))),
-    // 2023-01-30, 30.1.2023, 1/30/2023
+    // 2023-01-30, 30.1.2023, 30-1-2023, 1/30/2023
     (&Pattern::DateYmd, |c, v, r| {
         c.rule_allow_year_dates(r)?
             .date_ymd(v.get_int(0), v.get_int(1), v.get_int(2))?
             .rule_time_reset(r)
     }),
     (&Pattern::DateDmy, |c, v, r| {
+        let (month, day) = r.date_month_day(v.get_int(0), v.get_int(1), DateOrder::Dmy);
         c.rule_allow_year_dates(r)?
-            .date_ymd(v.get_int(2), v.get_int(1), v.get_int(0))?
+            .date_ymd(v.get_int(2), month, day)?
             .rule_time_reset(r)
     }),
     (&Pattern::DateMdy, |c, v, r| {
+        let (month, day) = r.date_month_day(v.get_int(0), v.get_int(1), DateOrder::Mdy);
         c.rule_allow_year_dates(r)?
-            .date_ymd(v.get_int(2), v.get_int(0), v.get_int(1))?
+            .date_ymd(v.get_int(2), month, day)?
             .rule_time_reset(r)
     }),
+    // 30.1.23, 30-1-23, 1/30/23
+    (&Pattern::DateDmyShort, |c, v, r| {
+        let (year, month, day) = r.date_short_ymd([v.get_int(0), v.get_int(1), v.get_int(2)], DateOrder::Dmy)?;
+        c.rule_allow_year_dates(r)?.date_ymd(year, month, day)?.rule_time_reset(r)
+    }),
+    (&Pattern::DateMdyShort, |c, v, r| {
+        let (year, month, day) = r.date_short_ymd([v.get_int(0), v.get_int(1), v.get_int(2)], DateOrder::Mdy)?;
+        c.rule_allow_year_dates(r)?.date_ymd(year, month, day)?.rule_time_reset(r)
+    }),
     // Dec 7, Dec 7th, 7 Dec
     (&Pattern::DateMonthDay, |c, v, r| c.date_ymd(c.rule_year(), v.get_int(0), v.get_int(1))?.rule_time_reset(r)),
     (&Pattern::DateMonthNth, |c, v, r| c.date_ymd(c.rule_year(), v.get_int(0), v.get_int(1))?.rule_time_reset(r)),
@@ -178,6 +427,11 @@ const FUZZY_PATTERNS: [(&Pattern, fn(FuzzyDate, &CallValues, &Rules) -> Result<F
             .date_ymd(v.get_int(2), v.get_int(0), v.get_int(1))?
             .rule_time_reset(r)
     }),
+    (&Pattern::DateMonthDayShortYear, |c, v, r| {
+        c.rule_allow_year_dates(r)?
+            .date_ymd(r.short_year(v.get_int(2))?, v.get_int(0), v.get_int(1))?
+            .rule_time_reset(r)
+    }),
     (&Pattern::DateMonthNthYear, |c, v, r| {
         c.rule_allow_year_dates(r)?
             .date_ymd(v.get_int(2), v.get_int(0), v.get_int(1))?
@@ -188,6 +442,11 @@ const FUZZY_PATTERNS: [(&Pattern, fn(FuzzyDate, &CallValues, &Rules) -> Result<F
             .date_ymd(v.get_int(2), v.get_int(1), v.get_int(0))?
             .rule_time_reset(r)
     }),
+    (&Pattern::DateDayMonthShortYear, |c, v, r| {
+        c.rule_allow_year_dates(r)?
+            .date_ymd(r.short_year(v.get_int(2))?, v.get_int(1), v.get_int(0))?
+            .rule_time_reset(r)
+    }),
     // Thu, 7 Dec
     (&Pattern::DateWdayDayMonth, |c, v, r| {
         c.date_ymd(c.rule_year(), v.get_int(2), v.get_int(1))?
@@ -225,6 +484,13 @@ const FUZZY_PATTERNS: [(&Pattern, fn(FuzzyDate, &CallValues, &Rules) -> Result<F
             .date_ymd(v.get_int(0), v.get_int(1), v.get_int(2))?
             .time_hms(v.get_int(3), v.get_int(4), v.get_int(5), v.get_ms(6))
     }),
+    // Z, +02:00, -0700
+    (&Pattern::Offset, |c, v, _| c.time_offset(v.get_int(0))),
+    // UTC, EST, Europe/London
+    (&Pattern::Zone, |c, v, _| match v.get_zone_name(0) {
+        Some(zone_index) => c.time_zone(zone_index),
+        None => c.time_offset(v.get_int(0)),
+    }),
     // 3:00, 3:00:00, 3:00:00.456
     (&Pattern::TimeHm, |c, v, _| c.time_hms(v.get_int(0), v.get_int(1), 0, 0)),
     (&Pattern::TimeHms, |c, v, _| c.time_hms(v.get_int(0), v.get_int(1), v.get_int(2), 0)),
@@ -236,11 +502,14 @@ const FUZZY_PATTERNS: [(&Pattern, fn(FuzzyDate, &CallValues, &Rules) -> Result<F
 
 #[derive(PartialEq)]
 enum TimeUnit {
+    BusinessDays,
     Days,
     Hours,
     Minutes,
     Months,
+    Quarters,
     Seconds,
+    Weekends,
     Weeks,
     Years,
     None,
@@ -256,9 +525,33 @@ impl TimeUnit {
             5 => Self::Weeks,
             6 => Self::Months,
             7 => Self::Years,
+            8 => Self::Quarters,
+            9 => Self::Weekends,
+            10 => Self::BusinessDays,
             _ => Self::None,
         }
     }
+
+    fn as_name(&self) -> &'static str {
+        match self {
+            Self::Seconds => "seconds",
+            Self::Minutes => "minutes",
+            Self::Hours => "hours",
+            Self::Days => "days",
+            Self::Weeks => "weeks",
+            Self::Months => "months",
+            Self::Quarters => "quarters",
+            Self::Weekends => "weekends",
+            Self::BusinessDays => "business days",
+            Self::Years => "years",
+            Self::None => "none",
+        }
+    }
+
+    /// Whether unit always has the same length in seconds
+    fn is_exact(&self) -> bool {
+        matches!(self, Self::Seconds | Self::Minutes | Self::Hours | Self::Days | Self::Weeks)
+    }
 }
 
 struct CallSequence {
@@ -320,6 +613,11 @@ impl CallSequence {
             return;
         }
 
+        // Explicit offset or zone is applied before anything else, so that dates
+        // and times of day given in the source are set within that offset
+        self.calls
+            .sort_by_key(|v| ![Pattern::Offset, Pattern::Zone].contains(&v.pattern_type));
+
         let order = self.get_allowed();
 
         if order.is_empty() {
@@ -344,13 +642,19 @@ impl CallSequence {
             return true;
         }
 
-        // We can't have more than one separate year defined
-        if let Some(indexes) = self.mapping.get(&Pattern::Year) {
-            if indexes.len().gt(&1) {
-                return false;
+        // We can't have more than one separate year or offset defined
+        for pattern_type in [Pattern::Year, Pattern::Offset, Pattern::Zone] {
+            if let Some(indexes) = self.mapping.get(&pattern_type) {
+                if indexes.len().gt(&1) {
+                    return false;
+                }
             }
         }
 
+        if self.patterns.contains(&Pattern::Offset) && self.patterns.contains(&Pattern::Zone) {
+            return false;
+        }
+
         let allowed = self.get_allowed();
 
         if allowed.is_empty() {
@@ -363,6 +667,8 @@ impl CallSequence {
 
     fn allowed_wday() -> Vec<Pattern> {
         let mut result = Vec::from([
+            Pattern::Offset,
+            Pattern::Zone,
             Pattern::ThisUnit,
             Pattern::PastUnit,
             Pattern::PrevUnit,
@@ -374,11 +680,10 @@ impl CallSequence {
     }
 }
 
-#[allow(dead_code)]
 struct CallPattern {
     pattern_type: Pattern,
     pattern_match: String,
-    callback: fn(FuzzyDate, &CallValues, &Rules) -> Result<FuzzyDate, ()>,
+    callback: fn(FuzzyDate, &CallValues, &Rules) -> Result<FuzzyDate, ParseError>,
     value_offset: usize,
 }
 
@@ -397,6 +702,48 @@ impl CallValues {
         self.tokens[index].value
     }
 
+    /// Get position of a named time zone in the time zone database,
+    /// or none when the value is an offset from UTC in seconds
+    fn get_zone_name(&self, index: usize) -> Option<usize> {
+        let index = self.position + index;
+
+        match self.tokens[index].token {
+            TokenType::ZoneName => Some(self.tokens[index].value as usize),
+            _ => None,
+        }
+    }
+
+    /// Get value as an amount in billionths, so that both integers
+    /// and decimals, e.g. "1.5", can be used as amounts of units
+    fn get_decimal(&self, index: usize) -> i128 {
+        let index = self.position + index;
+        let value = self.tokens[index].value as i128;
+
+        match self.tokens[index].token {
+            TokenType::Decimal => value,
+            _ => value * DECIMAL_SCALE as i128,
+        }
+    }
+
+    /// Get half as an amount in billionths, from a value that must
+    /// be one, e.g. "a" in "an hour and a half"
+    fn get_half(&self, index: usize) -> Result<i128, ParseError> {
+        match self.get_whole(index)?.eq(&1) {
+            true => Ok(DECIMAL_SCALE as i128 / 2),
+            false => Err(ParseError::NoMatchingPattern),
+        }
+    }
+
+    /// Get value as an integer, failing when it is a decimal
+    fn get_whole(&self, index: usize) -> Result<i64, ParseError> {
+        let index = self.position + index;
+
+        match self.tokens[index].token {
+            TokenType::Decimal => Err(ParseError::NoMatchingPattern),
+            _ => Ok(self.tokens[index].value),
+        }
+    }
+
     fn get_string(&self, index: usize) -> String {
         let index = self.position + index;
         let value = self.tokens[index].value;
@@ -446,58 +793,82 @@ impl CallValues {
 struct FuzzyDate {
     default_year: Option<i64>,
     time: DateTime<FixedOffset>,
+    zone: LocalZone,
 }
 
 impl FuzzyDate {
     /// Get a new instance of self with defaults
-    fn with_defaults(&self, new_time: DateTime<FixedOffset>) -> Self {
-        Self { default_year: self.default_year, time: new_time }
+    fn with_defaults(&self, new_time: DateTime<FixedOffset>) -> Result<Self, ParseError> {
+        Ok(Self { default_year: self.default_year, time: self.resolve_local(new_time)?, zone: self.zone })
     }
 
     /// Get a new instance of self without defaults
-    fn without_defaults(&self, new_time: DateTime<FixedOffset>) -> Self {
-        Self { default_year: None, time: new_time }
+    fn without_defaults(&self, new_time: DateTime<FixedOffset>) -> Result<Self, ParseError> {
+        Ok(Self { default_year: None, time: self.resolve_local(new_time)?, zone: self.zone })
+    }
+
+    /// Get a new instance of self with defaults, for time that was moved
+    /// by exact duration rather than by wall clock
+    fn with_instant(&self, new_time: DateTime<FixedOffset>) -> Self {
+        Self { default_year: self.default_year, time: self.zone.resolve_instant(new_time), zone: self.zone }
+    }
+
+    /// Get a new instance of self with defaults, for time that is fixed to
+    /// its own offset and no longer follows the time zone
+    fn with_fixed_offset(&self, new_time: DateTime<FixedOffset>) -> Self {
+        Self { default_year: self.default_year, time: new_time, zone: LocalZone::default() }
     }
 
     /// Set time to specific data from basic ISO8601 date string
-    fn date_iso8601(&self, value: String) -> Result<Self, ()> {
-        Ok(self.with_defaults(convert::date_iso8601(self.time, value)?))
+    fn date_iso8601(&self, value: String) -> Result<Self, ParseError> {
+        self.with_defaults(convert::date_iso8601(self.time, value)?)
     }
 
     /// Set time to specific timestamp
-    fn date_stamp(&self, sec: i64, ms: i64) -> Result<Self, ()> {
-        Ok(self.with_defaults(convert::date_stamp(sec, ms)))
+    fn date_stamp(&self, sec: i64, ms: i64) -> Result<Self, ParseError> {
+        Ok(self.with_fixed_offset(convert::date_stamp(sec, ms)))
+    }
+
+    /// Set time to the date of a holiday in specific year
+    fn date_holiday(&self, year: i64, holiday: i64, rules: &Rules) -> Result<Self, ParseError> {
+        let new_date = rules.holiday_date(holiday, year).ok_or(ParseError::InvalidDate)?;
+        self.date_ymd(year, new_date.month() as i64, new_date.day() as i64)
     }
 
     /// Set time to specific year and week number
-    fn date_yw(&self, year: i64, week: i64, rules: &Rules) -> Result<Self, ()> {
-        Ok(self.without_defaults(convert::date_yw(self.time, year, week, rules.week_start_day())?))
+    fn date_yw(&self, year: i64, week: i64, rules: &Rules) -> Result<Self, ParseError> {
+        self.without_defaults(convert::date_yw(self.time, year, week, rules.week_start_day())?)
     }
 
     /// Set time to specific year and month
-    fn date_ym(&self, year: i64, month: i64) -> Result<Self, ()> {
+    fn date_ym(&self, year: i64, month: i64) -> Result<Self, ParseError> {
         let month_day = convert::into_month_day(year as i32, month as u32, self.time.day());
-        Ok(self.without_defaults(convert::date_ymd(self.time, year, month, month_day as i64)?))
+        self.without_defaults(convert::date_ymd(self.time, year, month, month_day as i64)?)
+    }
+
+    /// Set time to the first day of specific year and quarter
+    fn date_yq(&self, year: i64, quarter: i64) -> Result<Self, ParseError> {
+        self.without_defaults(convert::date_yq(self.time, year, quarter)?)
     }
 
     /// Set time to specific year, month and day
-    fn date_ymd(&self, year: i64, month: i64, day: i64) -> Result<Self, ()> {
-        Ok(self.without_defaults(convert::date_ymd(self.time, year, month, day)?))
+    fn date_ymd(&self, year: i64, month: i64, day: i64) -> Result<Self, ParseError> {
+        self.without_defaults(convert::date_ymd(self.time, year, month, day)?)
     }
 
     /// Ensure that given value matches to allowed unit
-    fn ensure_unit(&self, given: TimeUnit, accept: TimeUnit) -> Result<Self, ()> {
+    fn ensure_unit(&self, given: TimeUnit, accept: TimeUnit) -> Result<Self, ParseError> {
         match given.eq(&accept) {
-            true => Ok(self.with_defaults(self.time)),
-            false => Err(()),
+            true => self.with_defaults(self.time),
+            false => Err(ParseError::UnsupportedUnit { unit: given.as_name() }),
         }
     }
 
     /// Ensure that the date has specified weekday
-    pub(crate) fn ensure_wday(&self, wday: i64) -> Result<Self, ()> {
+    pub(crate) fn ensure_wday(&self, wday: i64) -> Result<Self, ParseError> {
         match self.time.weekday().number_from_monday().eq(&(wday as u32)) {
-            true => Ok(self.with_defaults(self.time)),
-            false => Err(()),
+            true => self.with_defaults(self.time),
+            false => Err(ParseError::WeekdayMismatch),
         }
     }
 
@@ -507,35 +878,59 @@ impl FuzzyDate {
     }
 
     /// Move time into current or upcoming weekday
-    fn offset_current_weekday(&self, new_weekday: i64) -> Result<Self, ()> {
+    fn offset_current_weekday(&self, new_weekday: i64) -> Result<Self, ParseError> {
         match self.weekday().eq(&new_weekday) {
-            true => Ok(self.with_defaults(self.time)),
+            true => self.with_defaults(self.time),
             false => self.offset_weekday(new_weekday, Change::Next),
         }
     }
 
     /// Move time into previous or upcoming month
-    fn offset_month(&self, new_month: i64, change: Change) -> Result<Self, ()> {
-        Ok(self.with_defaults(convert::offset_month(self.time, new_month, change)))
+    fn offset_month(&self, new_month: i64, change: Change) -> Result<Self, ParseError> {
+        self.with_defaults(convert::offset_month(self.time, new_month, change))
+    }
+
+    /// Move time into previous or upcoming date of a holiday, looking
+    /// further than a year for holidays that are not held every year
+    fn offset_holiday(&self, holiday: i64, change: Change, rules: &Rules) -> Result<Self, ParseError> {
+        let today = self.time.date_naive();
+        let year = self.time.year() as i64;
+
+        let new_date = match change {
+            Change::Prev => (year - HOLIDAY_SEARCH_YEARS..=year)
+                .rev()
+                .find_map(|v| rules.holiday_date(holiday, v).filter(|d| d.lt(&today))),
+            _ => (year..=year + HOLIDAY_SEARCH_YEARS)
+                .find_map(|v| rules.holiday_date(holiday, v).filter(|d| d.gt(&today))),
+        };
+
+        let new_date = new_date.ok_or(ParseError::InvalidDate)?;
+        self.date_ymd(new_date.year() as i64, new_date.month() as i64, new_date.day() as i64)
     }
 
     /// Move time into previous or upcoming weekday
-    fn offset_weekday(&self, new_weekday: i64, change: Change) -> Result<Self, ()> {
-        Ok(self.with_defaults(convert::offset_weekday(self.time, new_weekday, change)))
+    fn offset_weekday(&self, new_weekday: i64, change: Change) -> Result<Self, ParseError> {
+        self.with_defaults(convert::offset_weekday(self.time, new_weekday, change))
     }
 
     /// Move time within month range
-    fn offset_range_month(&self, target: TimeUnit, month: i64, change: Change) -> Result<Self, ()> {
+    fn offset_range_month(&self, target: TimeUnit, month: i64, change: Change) -> Result<Self, ParseError> {
         if target.eq(&TimeUnit::Days) {
             let new_time = convert::offset_range_year_month(self.time, self.time.year() as i64, month, change)?;
-            return Ok(self.with_defaults(new_time));
+            return self.with_defaults(new_time);
         }
 
-        Err(())
+        Err(ParseError::UnsupportedUnit { unit: target.as_name() })
     }
 
     /// Move time within unit range
-    fn offset_range_unit(&self, target: TimeUnit, unit: TimeUnit, change: Change) -> Result<Self, ()> {
+    fn offset_range_unit(
+        &self,
+        target: TimeUnit,
+        unit: TimeUnit,
+        change: Change,
+        rules: &Rules,
+    ) -> Result<Self, ParseError> {
         if target.eq(&TimeUnit::Days) && unit.eq(&TimeUnit::Years) {
             if change.eq(&Change::Last) {
                 let last_day = convert::into_month_day(self.time.year(), 12, 31);
@@ -545,53 +940,178 @@ impl FuzzyDate {
             return self.date_ymd(self.time.year() as i64, 1, 1);
         }
 
+        if target.eq(&TimeUnit::Days) && unit.eq(&TimeUnit::Quarters) {
+            let quarter = convert::into_quarter(self.time.month());
+
+            if change.eq(&Change::Last) {
+                let new_time = convert::into_last_of_month(self.time, self.time.year() as i64, quarter * 3)?;
+                return self.without_defaults(new_time);
+            }
+
+            return self.date_yq(self.time.year() as i64, quarter);
+        }
+
+        if target.eq(&TimeUnit::BusinessDays) && unit.ne(&TimeUnit::Weekends) {
+            let from_end = change.eq(&Change::Last);
+            let range = self.offset_range_unit(TimeUnit::Days, unit, change, rules)?;
+            let change = match from_end {
+                true => Change::Last,
+                false => Change::First,
+            };
+            let new_time = convert::into_business_day(range.time, change, |v| rules.is_business_day(v))?;
+            return range.with_defaults(new_time);
+        }
+
+        if target.eq(&TimeUnit::Days) && unit.eq(&TimeUnit::Weekends) {
+            let (start_day, length) = rules.weekend_span()?;
+            let new_time = convert::offset_weekends(self.time, 0, start_day, length);
+
+            return match change.eq(&Change::Last) {
+                true => self.with_defaults(new_time + Duration::days(length - 1)),
+                false => self.with_defaults(new_time),
+            };
+        }
+
         if target.eq(&TimeUnit::Days) && unit.eq(&TimeUnit::Months) {
             if change.eq(&Change::Last) {
                 let last_day = convert::into_month_day(self.time.year(), self.time.month(), 31);
-                return Ok(self.with_defaults(self.time.with_day(last_day).unwrap()));
+                return self.with_defaults(self.time.with_day(last_day).unwrap());
             }
 
-            return Ok(self.with_defaults(self.time.with_day(1).unwrap()));
+            return self.with_defaults(self.time.with_day(1).unwrap());
         }
 
-        Err(())
+        match target.eq(&TimeUnit::Days) {
+            true => Err(ParseError::UnsupportedUnit { unit: unit.as_name() }),
+            false => Err(ParseError::UnsupportedUnit { unit: target.as_name() }),
+        }
     }
 
     /// Move time exactly by specified number of units
-    fn offset_unit_exact(&self, target: TimeUnit, amount: i64, _rules: &Rules) -> Result<FuzzyDate, ()> {
+    fn offset_unit_exact(&self, target: TimeUnit, amount: i64, rules: &Rules) -> Result<FuzzyDate, ParseError> {
         let new_time = match target {
-            TimeUnit::Seconds => self.time + Duration::seconds(amount),
-            TimeUnit::Minutes => self.time + Duration::minutes(amount),
-            TimeUnit::Hours => self.time + Duration::hours(amount),
+            TimeUnit::Seconds => return Ok(self.with_instant(self.time + Duration::seconds(amount))),
+            TimeUnit::Minutes => return Ok(self.with_instant(self.time + Duration::minutes(amount))),
+            TimeUnit::Hours => return Ok(self.with_instant(self.time + Duration::hours(amount))),
             TimeUnit::Days => self.time + Duration::days(amount),
             TimeUnit::Weeks => self.time + Duration::days(amount * 7),
             TimeUnit::Months => convert::offset_months(self.time, amount),
+            TimeUnit::Quarters => convert::offset_months(self.time, amount * 3),
             TimeUnit::Years => convert::offset_years(self.time, amount),
+            TimeUnit::BusinessDays => convert::offset_business_days(self.time, amount, |v| rules.is_business_day(v))?,
+            TimeUnit::Weekends => return Err(ParseError::UnsupportedUnit { unit: target.as_name() }),
             _ => self.time,
         };
 
-        Ok(self.with_defaults(new_time))
+        self.with_defaults(new_time)
+    }
+
+    /// Move time by an amount of units given in billionths, where the
+    /// fractional part is accepted for units from seconds to weeks
+    fn offset_unit_decimal(&self, target: TimeUnit, amount: i128, rules: &Rules) -> Result<FuzzyDate, ParseError> {
+        let scale = DECIMAL_SCALE as i128;
+        let whole = i64::try_from(amount / scale).map_err(|_| ParseError::OutOfRange)?;
+        let fraction = amount % scale;
+
+        let unit_seconds: i128 = match target {
+            TimeUnit::Seconds => 1,
+            TimeUnit::Minutes => 60,
+            TimeUnit::Hours => 3_600,
+            TimeUnit::Days => 86_400,
+            TimeUnit::Weeks => 604_800,
+            _ if fraction.ne(&0) => return Err(ParseError::UnsupportedUnit { unit: target.as_name() }),
+            _ => 0,
+        };
+
+        let new_self = self.offset_unit_exact(target, whole, rules)?;
+
+        if fraction.eq(&0) {
+            return Ok(new_self);
+        }
+
+        let milliseconds = (fraction * unit_seconds * 1_000 / scale) as i64;
+        Ok(new_self.with_instant(new_self.time + Duration::milliseconds(milliseconds)))
     }
 
     /// Move time by specific unit, but apply keyword rules where
     /// e.g. moving by weeks will land on to first day of week
-    fn offset_unit_keyword(&self, target: TimeUnit, amount: i64, rules: &Rules) -> Result<FuzzyDate, ()> {
+    fn offset_unit_keyword(&self, target: TimeUnit, amount: i64, rules: &Rules) -> Result<FuzzyDate, ParseError> {
         let new_time = match target {
             TimeUnit::Weeks => convert::offset_weeks(self.time, amount, rules.week_start_day()),
+            TimeUnit::Weekends => {
+                let (start_day, length) = rules.weekend_span()?;
+                convert::offset_weekends(self.time, amount, start_day, length)
+            }
             _ => return self.offset_unit_exact(target, amount, rules),
         };
 
-        Ok(self.with_defaults(new_time))
+        self.with_defaults(new_time)
     }
 
     /// Move time within year and month range
-    fn offset_range_year_month(&self, target: TimeUnit, year: i64, month: i64, change: Change) -> Result<Self, ()> {
+    fn offset_range_year_month(
+        &self,
+        target: TimeUnit,
+        year: i64,
+        month: i64,
+        change: Change,
+    ) -> Result<Self, ParseError> {
         if target.eq(&TimeUnit::Days) {
             let new_time = convert::offset_range_year_month(self.time, year, month, change)?;
-            return Ok(self.with_defaults(new_time));
+            return self.with_defaults(new_time);
         }
 
-        Err(())
+        Err(ParseError::UnsupportedUnit { unit: target.as_name() })
+    }
+
+    /// Move time within months of given fiscal year
+    fn offset_range_fiscal(
+        &self,
+        target: TimeUnit,
+        fiscal_year: i64,
+        months: Range<i64>,
+        change: Change,
+        rules: &Rules,
+    ) -> Result<Self, ParseError> {
+        if target.eq(&TimeUnit::Days) {
+            let new_time = convert::offset_range_fiscal(self.time, fiscal_year, rules.fiscal_start, months, change)?;
+            return self.with_defaults(new_time);
+        }
+
+        Err(ParseError::UnsupportedUnit { unit: target.as_name() })
+    }
+
+    /// Move time into first day of given quarter of fiscal year
+    fn offset_range_fiscal_quarter(&self, fiscal_year: i64, quarter: i64, rules: &Rules) -> Result<Self, ParseError> {
+        if quarter.lt(&1) || quarter.gt(&4) {
+            return Err(ParseError::OutOfRange);
+        }
+
+        let first_month = (quarter - 1) * 3;
+        self.offset_range_fiscal(TimeUnit::Days, fiscal_year, first_month..first_month + 3, Change::First, rules)
+    }
+
+    /// Move time within current fiscal year or quarter, after moving
+    /// it by given amount of fiscal years or quarters
+    fn offset_range_fiscal_unit(
+        &self,
+        target: TimeUnit,
+        unit: TimeUnit,
+        amount: i64,
+        change: Change,
+        rules: &Rules,
+    ) -> Result<Self, ParseError> {
+        let (fiscal_year, quarter) =
+            convert::into_fiscal_quarter(self.time.year() as i64, self.month(), rules.fiscal_start);
+
+        match unit {
+            TimeUnit::Years => self.offset_range_fiscal(target, fiscal_year + amount, 0..12, change, rules),
+            TimeUnit::Quarters => {
+                let first_month = (quarter - 1 + amount) * 3;
+                self.offset_range_fiscal(target, fiscal_year, first_month..first_month + 3, change, rules)
+            }
+            _ => Err(ParseError::UnsupportedUnit { unit: unit.as_name() }),
+        }
     }
 
     /// Move time to a weekday within year and month range
@@ -601,16 +1121,64 @@ impl FuzzyDate {
         month: i64,
         wday: i64,
         change: Change,
-    ) -> Result<Self, ()> {
+    ) -> Result<Self, ParseError> {
         let new_time = convert::offset_range_year_month_wday(self.time, year, month, wday, change)?;
-        Ok(self.without_defaults(new_time))
+        self.without_defaults(new_time)
+    }
+
+    /// Move time to nth weekday of year, or of month in the year
+    fn offset_nth_wday(
+        &self,
+        year: i64,
+        month: Option<i64>,
+        wday: i64,
+        nth: i64,
+        change: Change,
+    ) -> Result<Self, ParseError> {
+        self.without_defaults(convert::offset_nth_wday(self.time, year, month, wday, nth, change)?)
+    }
+
+    /// Move time to nth weekday of current month or year, after moving
+    /// it by given amount of months or years
+    fn offset_nth_wday_unit(
+        &self,
+        unit: TimeUnit,
+        amount: i64,
+        wday: i64,
+        nth: i64,
+        change: Change,
+        rules: &Rules,
+    ) -> Result<Self, ParseError> {
+        let within_month = match unit {
+            TimeUnit::Months => true,
+            TimeUnit::Years => false,
+            _ => return Err(ParseError::UnsupportedUnit { unit: unit.as_name() }),
+        };
+
+        let new_self = self.offset_unit_exact(unit, amount, rules)?;
+
+        let month = match within_month {
+            true => Some(new_self.month()),
+            false => None,
+        };
+
+        new_self.offset_nth_wday(new_self.time.year() as i64, month, wday, nth, change)
+    }
+
+    /// Resolve local time in time zone, unless time was not changed at all,
+    /// so that already resolved ambiguous times are kept as they are
+    fn resolve_local(&self, new_time: DateTime<FixedOffset>) -> Result<DateTime<FixedOffset>, ParseError> {
+        match new_time.eq(&self.time) {
+            true => Ok(new_time),
+            false => self.zone.resolve_local(new_time),
+        }
     }
 
     /// Ensure that rules allow changing the year
-    fn rule_allow_year_dates(&self, rules: &Rules) -> Result<Self, ()> {
+    fn rule_allow_year_dates(&self, rules: &Rules) -> Result<Self, ParseError> {
         match rules.date_years {
-            true => Ok(self.with_defaults(self.time)),
-            false => Err(()),
+            true => self.with_defaults(self.time),
+            false => Err(ParseError::NoMatchingPattern),
         }
     }
 
@@ -620,21 +1188,38 @@ impl FuzzyDate {
     }
 
     /// Reset time to midnight, if rules allow it
-    fn rule_time_reset(&self, rules: &Rules) -> Result<Self, ()> {
+    fn rule_time_reset(&self, rules: &Rules) -> Result<Self, ParseError> {
         match rules.reset_time {
             true => self.time_hms(0, 0, 0, 0),
-            false => Ok(self.with_defaults(self.time)),
+            false => self.with_defaults(self.time),
         }
     }
 
     /// Set time to specific hour, minute and second using 12-hour clock
-    fn time_12h(&self, hour: i64, min: i64, sec: i64, meridiem: i64) -> Result<Self, ()> {
-        Ok(self.with_defaults(convert::time_12h(self.time, hour, min, sec, meridiem)?))
+    fn time_12h(&self, hour: i64, min: i64, sec: i64, meridiem: i64) -> Result<Self, ParseError> {
+        self.with_defaults(convert::time_12h(self.time, hour, min, sec, meridiem)?)
+    }
+
+    /// Move time into specific UTC offset
+    fn time_offset(&self, offset_sec: i64) -> Result<Self, ParseError> {
+        Ok(self.with_fixed_offset(convert::time_offset(self.time, offset_sec)?))
+    }
+
+    /// Move time into named time zone, following its clock changes from there on
+    fn time_zone(&self, zone_index: usize) -> Result<Self, ParseError> {
+        let (new_time, new_zone) = convert::time_zone(self.time, zone_index, self.zone)?;
+        Ok(Self { default_year: self.default_year, time: new_time, zone: new_zone })
     }
 
     /// Set time to specific hour, minute and second
-    fn time_hms(&self, hour: i64, min: i64, sec: i64, ms: i64) -> Result<Self, ()> {
-        Ok(self.with_defaults(convert::time_hms(self.time, hour, min, sec, ms)?))
+    fn time_hms(&self, hour: i64, min: i64, sec: i64, ms: i64) -> Result<Self, ParseError> {
+        self.with_defaults(convert::time_hms(self.time, hour, min, sec, ms)?)
+    }
+
+    /// Set time to the time of day that a named period resolves into
+    fn time_period(&self, period: DayPeriod, rules: &Rules) -> Result<Self, ParseError> {
+        let (hour, min) = rules.day_period(period);
+        self.time_hms(hour, min, 0, 0)
     }
 
     /// Current weekday, matching to token values
@@ -643,13 +1228,134 @@ impl FuzzyDate {
     }
 }
 
+/// Steps taken to convert a source string into a datetime value
+#[derive(Debug)]
+pub struct Explanation {
+    /// Pattern that the source string was tokenized into
+    pub pattern: String,
+    /// Matched patterns, in the order they were applied
+    pub steps: Vec<ExplanationStep>,
+    pub date_years: bool,
+    pub reset_time: bool,
+    pub week_start_mon: bool,
+    pub result: Result<DateTime<FixedOffset>, ParseError>,
+}
+
+/// Single matched pattern, and the datetime value after applying it
+#[derive(Debug)]
+pub struct ExplanationStep {
+    pub pattern: Pattern,
+    /// Part of the pattern string that was matched
+    pub matched: String,
+    /// Datetime value after applying the pattern, or before it when it failed
+    pub time: DateTime<FixedOffset>,
+    /// Error from applying the pattern, if any
+    pub error: Option<ParseError>,
+}
+
+/// Preferences for reading the source string, given by the caller
+pub(crate) struct Settings {
+    pub(crate) date_order: DateOrder,
+    pub(crate) day_periods: HashMap<DayPeriod, (i64, i64)>,
+    pub(crate) fiscal_start: i64,
+    pub(crate) holidays: Option<Arc<dyn HolidayCalendar>>,
+    pub(crate) holiday_rules: HolidayRules,
+    pub(crate) two_digit_year: TwoDigitYear,
+    pub(crate) week_start_mon: bool,
+    pub(crate) weekend: WeekendDays,
+}
+
 struct Rules {
+    current_year: i64,
+    date_order: DateOrder,
     date_years: bool,
+    day_periods: HashMap<DayPeriod, (i64, i64)>,
+    fiscal_start: i64,
+    holidays: Option<Arc<dyn HolidayCalendar>>,
+    holiday_rules: HolidayRules,
     reset_time: bool,
+    two_digit_year: TwoDigitYear,
     week_start_mon: bool,
+    weekend: WeekendDays,
 }
 
 impl Rules {
+    /// Order of values in a numeric date, using the order of the date
+    /// separator when the reading is ambiguous
+    fn date_order(&self, first: i64, second: i64, separator_order: DateOrder) -> DateOrder {
+        match self.date_order {
+            DateOrder::Auto if first.gt(&12) => DateOrder::Dmy,
+            DateOrder::Auto if second.gt(&12) => DateOrder::Mdy,
+            DateOrder::Auto => separator_order,
+            order => order,
+        }
+    }
+
+    /// Hour and minute of a named period of a day, e.g. "morning"
+    fn day_period(&self, period: DayPeriod) -> (i64, i64) {
+        self.day_periods.get(&period).copied().unwrap_or(period.default_time())
+    }
+
+    /// Month and day from the first two values of a numeric date
+    /// that ends with a year, e.g. "04/01/2023"
+    fn date_month_day(&self, first: i64, second: i64, separator_order: DateOrder) -> (i64, i64) {
+        match self.date_order(first, second, separator_order) {
+            DateOrder::Dmy => (second, first),
+            _ => (first, second),
+        }
+    }
+
+    /// Year, month and day from the values of a numeric date
+    /// that has a two-digit year, e.g. "04/01/23"
+    fn date_short_ymd(&self, values: [i64; 3], separator_order: DateOrder) -> Result<(i64, i64, i64), ParseError> {
+        let (year, month, day) = match self.date_order(values[0], values[1], separator_order) {
+            DateOrder::Dmy => (values[2], values[1], values[0]),
+            DateOrder::Ymd => (values[0], values[1], values[2]),
+            _ => (values[2], values[0], values[1]),
+        };
+
+        Ok((self.short_year(year)?, month, day))
+    }
+
+    /// Fiscal year from either a full or a two-digit year value,
+    /// e.g. "FY2025" or "FY25"
+    fn fiscal_year(&self, year: i64) -> Result<i64, ParseError> {
+        match year.ge(&1000) {
+            true => Ok(year),
+            false => self.short_year(year),
+        }
+    }
+
+    /// Full year from a two-digit year value
+    fn short_year(&self, year: i64) -> Result<i64, ParseError> {
+        match year.ge(&0) && year.lt(&100) {
+            true => Ok(convert::full_year(self.current_year, year, self.two_digit_year)),
+            false => Err(ParseError::OutOfRange),
+        }
+    }
+
+    /// Date of a named holiday in given year, if it is held that year
+    fn holiday_date(&self, holiday: i64, year: i64) -> Option<NaiveDate> {
+        self.holiday_rules.date(holiday, year)
+    }
+
+    /// Whether date is neither a weekend day nor a holiday
+    fn is_business_day(&self, date: NaiveDate) -> bool {
+        let weekday = date.weekday().number_from_monday() as i64;
+
+        match &self.holidays {
+            Some(holidays) => !self.weekend.contains(weekday) && !holidays.is_holiday(date),
+            None => !self.weekend.contains(weekday),
+        }
+    }
+
+    /// First weekday of the weekend and the number of weekend days
+    fn weekend_span(&self) -> Result<(i64, i64), ParseError> {
+        self.weekend
+            .span()
+            .ok_or(ParseError::UnsupportedUnit { unit: TimeUnit::Weekends.as_name() })
+    }
+
     fn week_start_day(&self) -> i8 {
         match self.week_start_mon {
             true => 1,
@@ -659,120 +1365,489 @@ impl Rules {
 }
 
 /// Perform conversion against pattern and corresponding token values,
-/// relative to given datetime
+/// relative to given datetime, and record the steps taken when explaining
 pub(crate) fn convert(
     pattern: &str,
     tokens: Vec<Token>,
     current_time: &DateTime<FixedOffset>,
-    week_start_mon: bool,
+    settings: &Settings,
     custom_patterns: HashMap<String, String>,
-) -> Option<DateTime<FixedOffset>> {
+    local_zone: LocalZone,
+    mut explanation: Option<&mut Explanation>,
+) -> Result<DateTime<FixedOffset>, ParseError> {
     let call_list = find_pattern_calls(&pattern, custom_patterns);
     let mut call_sequence = CallSequence::new(call_list);
 
-    if call_sequence.calls.is_empty() {
-        return None;
-    }
-
-    if !call_sequence.validate() {
-        return None;
-    }
+    let current_year = current_time.year() as i64;
 
-    call_sequence.sort();
+    // Two-digit year tokens, e.g. "'23", are turned into full years
+    // before anything else makes use of them
+    let tokens = tokens
+        .into_iter()
+        .map(|v| match v.token.eq(&TokenType::Year) && v.value.lt(&100) {
+            true => Token::new(TokenType::Year, convert::full_year(current_year, v.value, settings.two_digit_year)),
+            false => v,
+        })
+        .collect::<Vec<Token>>();
 
     let mut ctx_vals = CallValues::from_tokens(tokens);
-    let mut ctx_time =
-        FuzzyDate { time: current_time.to_owned(), default_year: call_sequence.get_default_year(&ctx_vals) };
+    let mut ctx_time = FuzzyDate {
+        time: current_time.to_owned(),
+        default_year: call_sequence.get_default_year(&ctx_vals),
+        zone: local_zone,
+    };
 
     let rules = Rules {
+        current_year: current_year,
+        date_order: settings.date_order,
         date_years: ctx_time.default_year.is_none(),
+        day_periods: settings.day_periods.to_owned(),
+        fiscal_start: settings.fiscal_start,
+        holidays: settings.holidays.to_owned(),
+        holiday_rules: settings.holiday_rules.to_owned(),
         reset_time: call_sequence.should_reset_time(),
-        week_start_mon: week_start_mon,
+        two_digit_year: settings.two_digit_year,
+        week_start_mon: settings.week_start_mon,
+        weekend: settings.weekend,
     };
 
+    if let Some(explanation) = explanation.as_deref_mut() {
+        explanation.date_years = rules.date_years;
+        explanation.reset_time = rules.reset_time;
+    }
+
+    if call_sequence.calls.is_empty() || !call_sequence.validate() {
+        return Err(ParseError::NoMatchingPattern);
+    }
+
+    call_sequence.sort();
+
+    let has_zone = call_sequence.patterns.contains(&Pattern::Zone);
+
     for item in call_sequence.calls {
         ctx_vals.position = item.value_offset;
-        ctx_time = match (item.callback)(ctx_time, &ctx_vals, &rules) {
-            Ok(value) => value,
-            Err(_) => return None,
-        };
+        let prev_time = ctx_time.time;
+        let result = (item.callback)(ctx_time, &ctx_vals, &rules);
+
+        if let Some(explanation) = explanation.as_deref_mut() {
+            explanation.steps.push(ExplanationStep {
+                pattern: item.pattern_type,
+                matched: item.pattern_match,
+                time: result.as_ref().map(|v| v.time).unwrap_or(prev_time),
+                error: result.as_ref().err().cloned(),
+            });
+        }
+
+        ctx_time = result?;
     }
 
-    Some(ctx_time.time)
+    // Time resolved within a separately given zone is returned
+    // converted back into the offset of the current time
+    if has_zone {
+        let back_time = ctx_time.time.with_timezone(current_time.offset());
+        return Ok(local_zone.resolve_instant(back_time));
+    }
+
+    Ok(ctx_time.time)
 }
 
-/// Turn seconds into a duration string
-pub(crate) fn to_duration(seconds: f64, units: &UnitNames, max_unit: &str, min_unit: &str) -> String {
-    let mut seconds = seconds;
-    let mut result: String = String::new();
+/// Describe target time as a calendar phrase relative to current time,
+/// e.g. "Yesterday at 14:00", "Last Friday" or "April 1, 2022"
+pub(crate) fn to_calendar(
+    current_time: &DateTime<FixedOffset>,
+    target: DateTime<FixedOffset>,
+    week_start_day: i8,
+    phrases: &PhraseNames,
+    custom_tokens: &HashMap<String, Token>,
+) -> String {
+    let target = target.with_timezone(&current_time.timezone());
+    let days = (target.date_naive() - current_time.date_naive()).num_days();
+
+    let target_week = convert::offset_weeks(target, 0, week_start_day).date_naive();
+    let current_week = convert::offset_weeks(*current_time, 0, week_start_day).date_naive();
+    let weeks = (target_week - current_week).num_days() / 7;
+
+    let weekday = token::token_name(custom_tokens, TokenType::Weekday, target.weekday().number_from_monday() as i64);
+    let month = token::token_name(custom_tokens, TokenType::Month, target.month() as i64);
+
+    let date = match target.year().eq(&current_time.year()) {
+        true => format!("{} {}", month, target.day()),
+        false => format!("{} {}, {}", month, target.day(), target.year()),
+    };
 
-    let naming: HashMap<&str, i8> = HashMap::from([
-        ("s", 1),
-        ("sec", 1),
-        ("min", 2),
-        ("mins", 2),
-        ("h", 3),
-        ("hr", 3),
-        ("hrs", 3),
-        ("d", 4),
-        ("day", 4),
-        ("days", 4),
-        ("w", 5),
-        ("week", 5),
-        ("weeks", 5),
-    ]);
+    let time = match target.time().eq(&NaiveTime::MIN) {
+        true => String::new(),
+        false => target.format("%-H:%M").to_string(),
+    };
 
-    let max_u: &i8 = naming.get(max_unit).unwrap_or(&5);
-    let min_u: &i8 = naming.get(min_unit).unwrap_or(&1);
+    phrases.format_calendar(days, weeks, &weekday, &date, &time)
+}
+
+/// Turn seconds into a duration string
+pub(crate) fn to_duration(
+    seconds: f64,
+    units: &UnitNames,
+    max_unit: &str,
+    min_unit: &str,
+    sign: SignStyle,
+    precision: usize,
+    rounding: Rounding,
+) -> String {
+    let negative = seconds.lt(&0.0);
+    let mut result: String = String::new();
+    let (max_u, min_u) = duration_units(max_unit, min_unit);
+
+    // Time is rounded for the lowest unit shown before splitting it into
+    // units, so that rounding carries over, e.g. 59.9996 seconds into "1min"
+    let micros = (seconds.abs() * 1_000_000.0).round() as i64;
+    let (mut micros, min_u) = match precision.gt(&0) {
+        true => duration_precision(micros, max_u, min_u, precision, rounding),
+        false => (duration_round(micros, min_u), min_u),
+    };
 
     if max_u.ge(&5) && min_u.le(&5) {
-        let weeks = (seconds / 604800.0).floor() as i32;
+        let weeks = micros / 604_800_000_000;
 
         if weeks.gt(&0) {
-            result.push_str(&units.format_weeks(weeks));
-            seconds -= (weeks * 604800) as f64;
+            result.push_str(&units.format_weeks(weeks as i32));
+            micros %= 604_800_000_000;
         }
     }
 
     if max_u.ge(&4) && min_u.le(&4) {
-        let days = (seconds / 86400.0).floor() as i32;
+        let days = micros / 86_400_000_000;
 
         if days.gt(&0) {
-            result.push_str(&units.format_days(days));
-            seconds -= (days * 86400) as f64;
+            result.push_str(&units.format_days(days as i32));
+            micros %= 86_400_000_000;
         }
     }
 
     if max_u.ge(&3) && min_u.le(&3) {
-        let hours = (seconds / 3600.0).floor() as i32;
+        let hours = micros / 3_600_000_000;
 
         if hours.gt(&0) {
-            result.push_str(&units.format_hours(hours));
-            seconds -= (hours * 3600) as f64;
+            result.push_str(&units.format_hours(hours as i32));
+            micros %= 3_600_000_000;
         }
     }
 
     if max_u.ge(&2) && min_u.le(&2) {
-        let minutes = (seconds / 60.0).floor() as i32;
+        let minutes = micros / 60_000_000;
 
         if minutes.gt(&0) {
-            result.push_str(&units.format_minutes(minutes));
-            seconds -= (minutes * 60) as f64;
+            result.push_str(&units.format_minutes(minutes as i32));
+            micros %= 60_000_000;
         }
     }
 
+    // Units from seconds down can keep a fraction when they are
+    // the lowest unit shown, e.g. "1.25sec" or "2.5ms"
     if max_u.ge(&1) && min_u.le(&1) {
+        let seconds = duration_amount(micros, 1_000_000, min_u.eq(&1));
+
         if seconds.gt(&0.0) {
-            result.push_str(&units.format_seconds(seconds as i32));
+            result.push_str(&units.format_seconds(seconds));
+            micros %= 1_000_000;
+        }
+    }
+
+    if max_u.ge(&0) && min_u.le(&0) {
+        let milliseconds = duration_amount(micros, 1_000, min_u.eq(&0));
+
+        if milliseconds.gt(&0.0) {
+            result.push_str(&units.format_milliseconds(milliseconds));
+            micros %= 1_000;
+        }
+    }
+
+    if max_u.ge(&-1) && min_u.le(&-1) {
+        if micros.gt(&0) {
+            result.push_str(&units.format_microseconds(micros as f64));
+        }
+    }
+
+    match result.is_empty() {
+        true => result,
+        false => units.format_sign(result.trim().to_string(), negative, sign),
+    }
+}
+
+/// Round microseconds to the three decimals kept by the lowest unit shown,
+/// when it's seconds or lower, e.g. 59.9996 seconds into 60 seconds
+fn duration_round(micros: i64, min_u: i8) -> i64 {
+    let step = match DURATION_UNITS.iter().find(|(u, _)| u.eq(&min_u)) {
+        Some((u, unit_micros)) if u.le(&1) => cmp::max(unit_micros / 1_000, 1),
+        _ => 1,
+    };
+
+    (micros + step / 2) / step * step
+}
+
+/// Round microseconds into the lowest unit shown when keeping only given number of
+/// the most significant non-zero units, or into the minimum unit if there are fewer
+fn duration_precision(micros: i64, max_u: i8, min_u: i8, precision: usize, rounding: Rounding) -> (i64, i8) {
+    let shown_units = DURATION_UNITS.iter().filter(|(u, _)| u.le(&max_u) && u.ge(&min_u));
+    let mut remaining = micros;
+    let mut non_zero: usize = 0;
+    let mut lowest = DURATION_UNITS.iter().find(|(u, _)| u.eq(&min_u)).unwrap_or(&(1, 1_000_000));
+
+    for unit in shown_units {
+        non_zero += if (remaining / unit.1).gt(&0) { 1 } else { 0 };
+        remaining %= unit.1;
+
+        if non_zero.eq(&precision) {
+            lowest = unit;
+            break;
+        }
+    }
+
+    let (lowest_u, unit_micros) = *lowest;
+    let below = micros % unit_micros;
+
+    let rounded = match rounding {
+        Rounding::HalfUp if (below * 2).ge(&unit_micros) => micros - below + unit_micros,
+        Rounding::Ceil if below.gt(&0) => micros - below + unit_micros,
+        _ => micros - below,
+    };
+
+    (rounded, lowest_u)
+}
+
+/// Amount of unit in microseconds, as a whole number, or with up
+/// to three decimals when it's the lowest unit shown
+fn duration_amount(micros: i64, unit_micros: i64, is_lowest: bool) -> f64 {
+    match is_lowest {
+        true => (micros as f64 / unit_micros as f64 * 1_000.0).round() / 1_000.0,
+        false => (micros / unit_micros) as f64,
+    }
+}
+
+/// Convert number of seconds into an ISO 8601 duration string, e.g. "PT1H4M",
+/// showing weeks as days, since ISO 8601 doesn't allow combining the two
+pub(crate) fn to_iso_duration(seconds: f64, max_unit: &str, min_unit: &str) -> String {
+    let sign = if seconds.lt(&0.0) { "-" } else { "" };
+    let mut seconds = seconds.abs();
+    let mut date_part: String = String::new();
+    let mut time_part: String = String::new();
+    let (max_u, min_u) = duration_units(max_unit, min_unit);
+    let max_u = cmp::min(max_u, 4);
+
+    let parts: [(i8, f64, &str); 3] = [(4, 86400.0, "D"), (3, 3600.0, "H"), (2, 60.0, "M")];
+
+    for (unit, unit_seconds, designator) in parts {
+        if max_u.lt(&unit) || min_u.gt(&unit) {
+            continue;
+        }
+
+        let amount = (seconds / unit_seconds).floor();
+
+        if amount.gt(&0.0) {
+            let part = match unit.eq(&4) {
+                true => &mut date_part,
+                false => &mut time_part,
+            };
+
+            part.push_str(&format!("{}{}", amount, designator));
+            seconds -= amount * unit_seconds;
+        }
+    }
+
+    if min_u.le(&1) && seconds.gt(&0.0) {
+        let amount = format!("{:.3}", seconds);
+        let amount = amount.trim_end_matches('0').trim_end_matches('.');
+
+        if !amount.eq("0") {
+            time_part.push_str(&format!("{}S", amount));
+        }
+    }
+
+    // Duration always needs at least one component, which for zero
+    // durations is the lowest unit shown
+    if date_part.is_empty() && time_part.is_empty() {
+        return match min_u.ge(&4) {
+            true => String::from("P0D"),
+            false => String::from("PT0S"),
+        };
+    }
+
+    match time_part.is_empty() {
+        true => format!("{}P{}", sign, date_part),
+        false => format!("{}P{}T{}", sign, date_part, time_part),
+    }
+}
+
+/// Parse an ISO 8601 duration string, e.g. "PT1H30M" or "P1W", into seconds,
+/// or return nothing when the string doesn't look like one
+pub(crate) fn from_iso_duration(source: &str) -> Option<Result<f64, ParseError>> {
+    let (sign, value) = match source.strip_prefix("-") {
+        Some(value) => (-1.0, value),
+        None => (1.0, source.strip_prefix("+").unwrap_or(source)),
+    };
+
+    let value = value.strip_prefix(['P', 'p'])?;
+
+    if !value.starts_with(|c: char| c.is_ascii_digit() || c.eq_ignore_ascii_case(&'T')) {
+        return None;
+    }
+
+    Some(iso_duration_seconds(value).map(|v| sign * v))
+}
+
+/// Sum of seconds in the components of an ISO 8601 duration, given
+/// without the leading "P", with components required to be in order
+fn iso_duration_seconds(value: &str) -> Result<f64, ParseError> {
+    let designators: [(&str, f64); 7] = [
+        ("Y", 0.0),
+        ("M", 0.0),
+        ("W", 604800.0),
+        ("D", 86400.0),
+        ("H", 3600.0),
+        ("M", 60.0),
+        ("S", 1.0),
+    ];
+
+    let (date_part, time_part) = match value.split_once(['T', 't']) {
+        Some((_, "")) => return Err(ParseError::NoMatchingPattern),
+        Some((date_part, time_part)) => (date_part, time_part),
+        None => (value, ""),
+    };
+
+    let mut result: f64 = 0.0;
+
+    for (part, allowed) in [(date_part, 0..4), (time_part, 4..7)] {
+        let mut position = allowed.start;
+        let mut amount = String::new();
+
+        for part_char in part.chars() {
+            if part_char.is_ascii_digit() || part_char.eq(&'.') || part_char.eq(&',') {
+                amount.push(if part_char.eq(&',') { '.' } else { part_char });
+                continue;
+            }
+
+            let designator = part_char.to_ascii_uppercase().to_string();
+            let index = (position..allowed.end)
+                .find(|v| designators[*v].0.eq(&designator))
+                .ok_or(ParseError::NoMatchingPattern)?;
+
+            let amount_value = amount.parse::<f64>().map_err(|_| ParseError::NoMatchingPattern)?;
+
+            match index {
+                0 => return Err(ParseError::UnsupportedUnit { unit: "years" }),
+                1 => return Err(ParseError::UnsupportedUnit { unit: "months" }),
+                _ => result += amount_value * designators[index].1,
+            }
+
+            position = index + 1;
+            amount.clear();
+        }
+
+        if !amount.is_empty() {
+            return Err(ParseError::NoMatchingPattern);
         }
     }
 
-    result.trim().to_string()
+    Ok(result)
+}
+
+/// Get name of the unit from its token value when the unit doesn't have
+/// an exact length in seconds, e.g. "months", or nothing otherwise
+pub(crate) fn inexact_unit_name(unit_value: i64) -> Option<&'static str> {
+    match TimeUnit::from_int(unit_value) {
+        TimeUnit::None => None,
+        unit if unit.is_exact() => None,
+        unit => Some(unit.as_name()),
+    }
+}
+
+/// Parse a compact duration string in the style of Go and systemd, e.g.
+/// "1h30m45s" or "250ms", into seconds, or return nothing when the string
+/// doesn't look like one
+pub(crate) fn from_compact_duration(source: &str) -> Option<Result<f64, ParseError>> {
+    let (sign, value) = match source.strip_prefix("-") {
+        Some(value) => (-1, value),
+        None => (1, source.strip_prefix("+").unwrap_or(source)),
+    };
+
+    let mut result: i128 = 0;
+    let mut parts = value.trim_start();
+
+    if parts.is_empty() {
+        return None;
+    }
+
+    while !parts.is_empty() {
+        let amount_length = parts.find(|c: char| !c.is_ascii_digit() && c.ne(&'.')).unwrap_or(parts.len());
+        let (amount, rest) = parts.split_at(amount_length);
+        let unit_length = rest.find(|c: char| !c.is_alphabetic()).unwrap_or(rest.len());
+        let (unit, rest) = rest.split_at(unit_length);
+
+        let unit_nanos = COMPACT_UNITS.iter().find(|v| v.0.eq(unit))?.1;
+        let (whole, fraction) = amount.split_once(".").unwrap_or((amount, ""));
+
+        if whole.is_empty() && fraction.is_empty() {
+            return None;
+        }
+
+        // Whole amount has only digits, so it fails to parse only when too large
+        let whole = match whole.is_empty() {
+            true => 0,
+            false => match whole.parse::<i128>() {
+                Ok(v) => v,
+                Err(_) => return Some(Err(ParseError::OutOfRange)),
+            },
+        };
+
+        // Fractions beyond nanoseconds are dropped
+        let fraction = &fraction[..cmp::min(fraction.len(), 9)];
+        let fraction_nanos = match fraction.is_empty() {
+            true => 0,
+            false => fraction.parse::<i128>().ok()? * unit_nanos / 10_i128.pow(fraction.len() as u32),
+        };
+
+        let unit_result = whole
+            .checked_mul(unit_nanos)
+            .and_then(|v| v.checked_add(fraction_nanos))
+            .and_then(|v| v.checked_add(result));
+
+        result = match unit_result {
+            Some(v) if v.le(&(i64::MAX as i128)) => v,
+            _ => return Some(Err(ParseError::OutOfRange)),
+        };
+
+        parts = rest.trim_start();
+    }
+
+    Some(Ok((sign * result) as f64 / 1_000_000_000.0))
+}
+
+/// Maximum and minimum unit of a duration string, from unit names
+/// such as "w" or "min", as numbers from -1 for microseconds to 5 for weeks
+fn duration_units(max_unit: &str, min_unit: &str) -> (i8, i8) {
+    let naming: HashMap<&str, i8> = HashMap::from([
+        ("us", -1),
+        ("ms", 0),
+        ("s", 1),
+        ("sec", 1),
+        ("min", 2),
+        ("mins", 2),
+        ("h", 3),
+        ("hr", 3),
+        ("hrs", 3),
+        ("d", 4),
+        ("day", 4),
+        ("days", 4),
+        ("w", 5),
+        ("week", 5),
+        ("weeks", 5),
+    ]);
+
+    (*naming.get(max_unit).unwrap_or(&5), *naming.get(min_unit).unwrap_or(&1))
 }
 
 /// Find closure calls that match the pattern exactly, or partially
 fn find_pattern_calls(pattern: &str, custom: HashMap<String, String>) -> Vec<CallPattern> {
-    let closure_map: HashMap<&Pattern, fn(FuzzyDate, &CallValues, &Rules) -> Result<FuzzyDate, ()>> =
+    let closure_map: HashMap<&Pattern, fn(FuzzyDate, &CallValues, &Rules) -> Result<FuzzyDate, ParseError>> =
         HashMap::from(FUZZY_PATTERNS);
 
     let pattern_keys = closure_map.keys().map(|v| v.to_owned()).collect::<HashSet<&Pattern>>();
@@ -780,10 +1855,20 @@ fn find_pattern_calls(pattern: &str, custom: HashMap<String, String>) -> Vec<Cal
 
     for (custom_pattern, closure_pattern) in custom.iter() {
         if let Some(pattern_constant) = pattern_map.get(closure_pattern) {
-            pattern_map.insert(custom_pattern.to_owned(), pattern_constant.to_owned());
+            pattern_map.insert(custom_pattern.to_lowercase(), pattern_constant.to_owned());
         }
     }
 
+    // Decimal amounts, e.g. "1.5 hours", are only accepted by patterns
+    // that read the amount as a decimal, in place of the first integer
+    let decimal_patterns = pattern_map
+        .iter()
+        .filter(|(_, v)| DECIMAL_PATTERNS.contains(v))
+        .map(|(k, v)| (k.replacen("[int]", "[decimal]", 1), v.to_owned()))
+        .collect::<Vec<(String, Pattern)>>();
+
+    pattern_map.extend(decimal_patterns);
+
     for prefix in vec!["", "+"] {
         let try_pattern = format!("{}{}", prefix, pattern);
 
@@ -843,6 +1928,8 @@ fn find_pattern_calls(pattern: &str, custom: HashMap<String, String>) -> Vec<Cal
 
 /// Figure out whether unit lengths in pattern are negative or positive
 fn find_pattern_prefix(pattern: &str, custom: HashMap<String, String>) -> &'static str {
+    let pattern = &pattern.replace("[decimal]", "[int]");
+
     if pattern.starts_with("-") {
         return "-";
     }
@@ -860,7 +1947,7 @@ fn find_pattern_prefix(pattern: &str, custom: HashMap<String, String>) -> &'stat
             }
 
             for (custom_pattern, closure_pattern) in custom.iter() {
-                if closure_pattern.eq(pattern_value) && pattern.ends_with(custom_pattern) {
+                if closure_pattern.eq(pattern_value) && pattern.ends_with(&custom_pattern.to_lowercase()) {
                     return "-";
                 }
             }
@@ -947,7 +2034,18 @@ mod tests {
             .map(|v| Token::new_integer(v.to_owned(), 0))
             .collect::<Vec<Token>>();
 
-        let result_time = convert(pattern, tokens, &current_time, false, custom_patterns);
+        let settings = Settings {
+            date_order: DateOrder::Auto,
+            day_periods: HashMap::new(),
+            fiscal_start: 1,
+            holidays: None,
+            holiday_rules: HolidayRules::default(),
+            two_digit_year: TwoDigitYear::default(),
+            week_start_mon: false,
+            weekend: WeekendDays::default(),
+        };
+        let result_time =
+            convert(pattern, tokens, &current_time, &settings, custom_patterns, LocalZone::default(), None);
         result_time.unwrap().to_string()
     }
 }
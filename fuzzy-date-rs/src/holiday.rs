@@ -0,0 +1,218 @@
+use crate::calendar::HolidayCalendar;
+use crate::token::{Token, TokenType};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::HashMap;
+
+// Holidays that are observed in all locales
+const COMMON_HOLIDAYS: [(&'static str, HolidayRule); 10] = [
+    ("new year", HolidayRule::Fixed { month: 1, day: 1 }),
+    ("new year's day", HolidayRule::Fixed { month: 1, day: 1 }),
+    ("new year's eve", HolidayRule::Fixed { month: 12, day: 31 }),
+    ("christmas", HolidayRule::Fixed { month: 12, day: 25 }),
+    ("christmas day", HolidayRule::Fixed { month: 12, day: 25 }),
+    ("christmas eve", HolidayRule::Fixed { month: 12, day: 24 }),
+    ("easter", HolidayRule::Easter { days: 0 }),
+    ("easter sunday", HolidayRule::Easter { days: 0 }),
+    ("easter monday", HolidayRule::Easter { days: 1 }),
+    ("good friday", HolidayRule::Easter { days: -2 }),
+];
+
+const US_HOLIDAYS: [(&'static str, HolidayRule); 4] = [
+    ("thanksgiving", HolidayRule::NthWeekday { month: 11, weekday: Weekday::Thu, nth: 4 }),
+    ("independence day", HolidayRule::Fixed { month: 7, day: 4 }),
+    ("memorial day", HolidayRule::NthWeekday { month: 5, weekday: Weekday::Mon, nth: -1 }),
+    ("labor day", HolidayRule::NthWeekday { month: 9, weekday: Weekday::Mon, nth: 1 }),
+];
+
+const GB_HOLIDAYS: [(&'static str, HolidayRule); 3] = [
+    ("boxing day", HolidayRule::Fixed { month: 12, day: 26 }),
+    ("spring bank holiday", HolidayRule::NthWeekday { month: 5, weekday: Weekday::Mon, nth: -1 }),
+    ("summer bank holiday", HolidayRule::NthWeekday { month: 8, weekday: Weekday::Mon, nth: -1 }),
+];
+
+const CA_HOLIDAYS: [(&'static str, HolidayRule); 4] = [
+    ("thanksgiving", HolidayRule::NthWeekday { month: 10, weekday: Weekday::Mon, nth: 2 }),
+    ("canada day", HolidayRule::Fixed { month: 7, day: 1 }),
+    ("labour day", HolidayRule::NthWeekday { month: 9, weekday: Weekday::Mon, nth: 1 }),
+    ("boxing day", HolidayRule::Fixed { month: 12, day: 26 }),
+];
+
+/// Rule for computing the date of a holiday in a given year
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HolidayRule {
+    /// Same month and day every year, e.g. December 25th for Christmas
+    Fixed { month: u32, day: u32 },
+    /// Nth weekday of a month, counted from the end when negative,
+    /// e.g. 4th Thursday of November for Thanksgiving
+    NthWeekday { month: u32, weekday: Weekday, nth: i8 },
+    /// Days from Easter Sunday, e.g. -2 for Good Friday
+    Easter { days: i64 },
+}
+
+impl HolidayRule {
+    /// Date of the holiday in given year, if it exists
+    pub fn date(&self, year: i32) -> Option<NaiveDate> {
+        match *self {
+            Self::Fixed { month, day } => NaiveDate::from_ymd_opt(year, month, day),
+            Self::NthWeekday { month, weekday, nth } if nth.gt(&0) => {
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, nth as u8)
+            }
+            Self::NthWeekday { month, weekday, nth } => {
+                let last_day = NaiveDate::from_ymd_opt(year, month, 1)?
+                    .checked_add_months(chrono::Months::new(1))?
+                    .pred_opt()?;
+                let days_back = (7 + last_day.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+                let new_date = last_day - Duration::days(days_back as i64) + Duration::weeks(nth as i64 + 1);
+                new_date.month().eq(&month).then_some(new_date)
+            }
+            Self::Easter { days } => easter_sunday(year).map(|v| v + Duration::days(days)),
+        }
+    }
+}
+
+/// Named holidays of a locale, e.g. Thanksgiving in the United States
+#[derive(Clone, Debug)]
+pub struct HolidayRules {
+    rules: Vec<(String, HolidayRule)>,
+}
+
+impl HolidayRules {
+    /// Empty set of holidays, with no names recognized
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Holidays of a country, given as a two-letter country code, e.g. "us",
+    /// "gb" or "ca", with holidays that are common to all countries included
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let local_holidays: &[(&'static str, HolidayRule)] = match locale.to_lowercase().as_str() {
+            "us" => &US_HOLIDAYS,
+            "gb" => &GB_HOLIDAYS,
+            "ca" => &CA_HOLIDAYS,
+            _ => return None,
+        };
+
+        let rules = COMMON_HOLIDAYS
+            .iter()
+            .chain(local_holidays.iter())
+            .map(|(name, rule)| (name.to_string(), rule.to_owned()))
+            .collect();
+
+        Some(Self { rules: rules })
+    }
+
+    /// Add a named holiday, replacing any previous holiday with the same name
+    pub fn add_rule(mut self, name: &str, rule: HolidayRule) -> Self {
+        let name = name.to_lowercase();
+        self.rules.retain(|v| v.0.ne(&name));
+        self.rules.push((name, rule));
+        self
+    }
+
+    /// Date of the holiday in given year, by the value of its token
+    pub(crate) fn date(&self, holiday: i64, year: i64) -> Option<NaiveDate> {
+        let (_, rule) = self.rules.get(usize::try_from(holiday).ok()?)?;
+        rule.date(i32::try_from(year).ok()?)
+    }
+
+    /// Holiday names as tokens, with the position of the holiday as the value
+    pub(crate) fn tokens(&self) -> HashMap<String, Token> {
+        self.rules
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| (name.to_owned(), Token::new(TokenType::Holiday, i as i64)))
+            .collect()
+    }
+}
+
+impl Default for HolidayRules {
+    fn default() -> Self {
+        Self::from_locale("us").unwrap()
+    }
+}
+
+impl HolidayCalendar for HolidayRules {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.rules
+            .iter()
+            .any(|(_, rule)| rule.date(date.year()).is_some_and(|v| v.eq(&date)))
+    }
+}
+
+/// Date of Easter Sunday in the Gregorian calendar
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_easter_sunday() {
+        let expect: Vec<(i32, &str)> = vec![
+            (2000, "2000-04-23"),
+            (2019, "2019-04-21"),
+            (2024, "2024-03-31"),
+            (2025, "2025-04-20"),
+            (2038, "2038-04-25"),
+        ];
+
+        for (year, expect_date) in expect {
+            assert_eq!(easter_sunday(year).unwrap().to_string(), expect_date);
+        }
+    }
+
+    #[test]
+    fn test_holiday_rule_dates() {
+        let expect: Vec<(HolidayRule, i32, Option<&str>)> = vec![
+            (HolidayRule::Fixed { month: 12, day: 25 }, 2024, Some("2024-12-25")),
+            (HolidayRule::Fixed { month: 2, day: 29 }, 2024, Some("2024-02-29")),
+            (HolidayRule::Fixed { month: 2, day: 29 }, 2023, None),
+            (HolidayRule::NthWeekday { month: 11, weekday: Weekday::Thu, nth: 4 }, 2024, Some("2024-11-28")),
+            (HolidayRule::NthWeekday { month: 5, weekday: Weekday::Mon, nth: -1 }, 2024, Some("2024-05-27")),
+            (HolidayRule::NthWeekday { month: 3, weekday: Weekday::Sun, nth: -1 }, 2024, Some("2024-03-31")),
+            (HolidayRule::NthWeekday { month: 12, weekday: Weekday::Sun, nth: -2 }, 2024, Some("2024-12-22")),
+            (HolidayRule::NthWeekday { month: 2, weekday: Weekday::Mon, nth: 5 }, 2024, None),
+            (HolidayRule::NthWeekday { month: 2, weekday: Weekday::Mon, nth: -5 }, 2024, None),
+            (HolidayRule::Easter { days: -2 }, 2024, Some("2024-03-29")),
+            (HolidayRule::Easter { days: 1 }, 2025, Some("2025-04-21")),
+        ];
+
+        for (rule, year, expect_date) in expect {
+            assert_eq!(rule.date(year).map(|v| v.to_string()), expect_date.map(|v| v.to_string()), "{:?}", rule);
+        }
+    }
+
+    #[test]
+    fn test_holiday_rules() {
+        assert!(HolidayRules::from_locale("fi").is_none());
+        assert!(HolidayRules::new().tokens().is_empty());
+
+        let rules = HolidayRules::from_locale("CA").unwrap();
+        let thanksgiving = rules.tokens().get("thanksgiving").unwrap().value;
+        assert_eq!(rules.date(thanksgiving, 2024).unwrap().to_string(), "2024-10-14");
+
+        let rules = HolidayRules::default().add_rule("Thanksgiving", HolidayRule::Fixed { month: 11, day: 1 });
+        let thanksgiving = rules.tokens().get("thanksgiving").unwrap().value;
+        assert_eq!(rules.date(thanksgiving, 2024).unwrap().to_string(), "2024-11-01");
+
+        assert!(rules.is_holiday(NaiveDate::from_ymd_opt(2024, 3, 29).unwrap()));
+        assert!(!rules.is_holiday(NaiveDate::from_ymd_opt(2024, 3, 28).unwrap()));
+    }
+}
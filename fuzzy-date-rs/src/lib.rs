@@ -1,17 +1,45 @@
+pub mod calendar;
 mod convert;
+pub mod error;
 mod fuzzy;
+pub mod holiday;
 pub mod pattern;
 pub mod token;
 
-use crate::token::{Token, UnitNames, UnitGroup, WeekStartDay};
-use chrono::{DateTime, Duration, FixedOffset, Utc};
+use crate::calendar::HolidayCalendar;
+use crate::convert::LocalZone;
+use crate::error::ParseError;
+use crate::holiday::HolidayRules;
+use crate::pattern::Pattern;
+#[cfg(feature = "chrono-tz")]
+use crate::token::{AmbiguousTime, SkippedTime};
+use crate::token::{
+    DateOrder, DayPeriod, PhraseNames, Rounding, SignStyle, Token, TwoDigitYear, UnitNames, UnitGroup, WeekStartDay,
+    WeekendDays,
+};
+use chrono::{DateTime, Duration, FixedOffset, Month, Utc};
+#[cfg(feature = "chrono-tz")]
+use chrono_tz::Tz;
 use std::collections::HashMap;
+use std::sync::Arc;
+
+pub use crate::fuzzy::{Explanation, ExplanationStep};
 
 pub struct FuzzyDate {
     current_time: DateTime<FixedOffset>,
     custom_patterns: HashMap<String, String>,
+    custom_phrases: HashMap<String, String>,
     custom_tokens: HashMap<String, Token>,
+    custom_units: HashMap<String, String>,
+    date_order: DateOrder,
+    day_periods: HashMap<DayPeriod, (i64, i64)>,
     first_weekday: WeekStartDay,
+    fiscal_year_start: Month,
+    holidays: Option<Arc<dyn HolidayCalendar>>,
+    holiday_rules: HolidayRules,
+    local_zone: LocalZone,
+    two_digit_year: TwoDigitYear,
+    weekend: WeekendDays,
 }
 
 impl FuzzyDate {
@@ -28,36 +56,210 @@ impl FuzzyDate {
         Self {
             current_time: current_time,
             custom_patterns: HashMap::new(),
+            custom_phrases: HashMap::new(),
             custom_tokens: HashMap::new(),
+            custom_units: HashMap::new(),
+            date_order: DateOrder::default(),
+            day_periods: HashMap::new(),
             first_weekday: WeekStartDay::Monday,
+            fiscal_year_start: Month::January,
+            holidays: None,
+            holiday_rules: HolidayRules::default(),
+            local_zone: LocalZone::default(),
+            two_digit_year: TwoDigitYear::default(),
+            weekend: WeekendDays::default(),
         }
     }
 
+    /// Use time zone for current time, so that moving time by days or
+    /// longer keeps the wall clock time over daylight saving changes
+    #[cfg(feature = "chrono-tz")]
+    pub fn from_time_tz(current_time: DateTime<Tz>) -> Self {
+        let mut value = Self::from_time(current_time.fixed_offset());
+        value.local_zone.time_zone = Some(current_time.timezone());
+        value
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    pub fn set_ambiguous_time(mut self, policy: AmbiguousTime) -> Self {
+        self.local_zone.ambiguous = policy;
+        self
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    pub fn set_skipped_time(mut self, policy: SkippedTime) -> Self {
+        self.local_zone.skipped = policy;
+        self
+    }
+
     pub fn set_custom_patterns(mut self, custom: HashMap<String, String>) -> Self {
         self.custom_patterns = custom;
         self
     }
 
+    /// Set phrases used in relative time strings and calendar phrases, e.g. "yesterday"
+    pub fn set_custom_phrases(mut self, phrases: HashMap<String, String>) -> Self {
+        self.custom_phrases = phrases;
+        self
+    }
+
     pub fn set_custom_tokens(mut self, custom: HashMap<String, Token>) -> Self {
         self.custom_tokens = custom;
         self
     }
 
+    /// Set long unit names used in relative time strings, e.g. "days" or "ago"
+    pub fn set_custom_units(mut self, units: HashMap<String, String>) -> Self {
+        self.custom_units = units;
+        self
+    }
+
+    /// Set how day and month are read from numeric dates
+    pub fn set_date_order(mut self, order: DateOrder) -> Self {
+        self.date_order = order;
+        self
+    }
+
+    /// Set hour and minute that named periods of a day, e.g. "morning" or
+    /// "EOD", resolve into, with periods not given using their default time
+    pub fn set_day_periods(mut self, periods: HashMap<DayPeriod, (u32, u32)>) -> Self {
+        self.day_periods = periods.into_iter().map(|(k, v)| (k, (v.0 as i64, v.1 as i64))).collect();
+        self
+    }
+
     pub fn set_first_weekday(mut self, weekday: WeekStartDay) -> Self {
         self.first_weekday = weekday;
         self
     }
 
+    /// Set the month that fiscal years start from, with fiscal years
+    /// named after the calendar year they end in
+    pub fn set_fiscal_year_start(mut self, month: Month) -> Self {
+        self.fiscal_year_start = month;
+        self
+    }
+
+    /// Set calendar of holidays that are skipped over, in addition to
+    /// weekend days, when moving time by business days
+    pub fn set_holiday_calendar(mut self, calendar: impl HolidayCalendar + 'static) -> Self {
+        self.holidays = Some(Arc::new(calendar));
+        self
+    }
+
+    /// Set named holidays that can be used in source strings, e.g.
+    /// "Christmas" or "next Thanksgiving", with US holidays by default
+    pub fn set_holiday_rules(mut self, rules: HolidayRules) -> Self {
+        self.holiday_rules = rules;
+        self
+    }
+
+    /// Set how the century of two-digit years is chosen
+    pub fn set_two_digit_year(mut self, policy: TwoDigitYear) -> Self {
+        self.two_digit_year = policy;
+        self
+    }
+
+    /// Set weekdays that make up the weekend, e.g. Friday and Saturday
+    pub fn set_weekend_days(mut self, weekend: WeekendDays) -> Self {
+        self.weekend = weekend;
+        self
+    }
+
     /// Tokenize source string and then convert it into a datetime value
-    pub fn to_datetime(&self, source: &str) -> Option<DateTime<FixedOffset>> {
-        let (pattern, tokens) = token::tokenize(&source, self.custom_tokens.to_owned());
-        fuzzy::convert(
+    pub fn to_datetime(&self, source: &str) -> Result<DateTime<FixedOffset>, ParseError> {
+        let custom_tokens = self.tokens();
+        let (pattern, tokens) = token::tokenize_pattern(&source, custom_tokens.to_owned());
+        let result = fuzzy::convert(
             &pattern,
             tokens,
             &self.current_time,
-            self.first_weekday.eq(&WeekStartDay::Monday),
+            &self.settings(),
             self.custom_patterns.to_owned(),
-        )
+            self.local_zone,
+            None,
+        );
+
+        result.map_err(|e| with_unknown_token(e, source, &custom_tokens, &self.custom_patterns))
+    }
+
+    /// Describe target time relative to current time, e.g. "3 days ago" or "in 2 hours"
+    pub fn to_relative(&self, target: DateTime<FixedOffset>) -> String {
+        let mut unit_names = UnitNames::from_name(&UnitGroup::Long);
+        unit_names.add_names(self.custom_units.to_owned());
+
+        let mut phrase_names = PhraseNames::from_map(PhraseNames::get_defaults());
+        phrase_names.add_names(self.custom_phrases.to_owned());
+
+        let target_date = target.with_timezone(&self.current_time.timezone()).date_naive();
+        let days = (target_date - self.current_time.date_naive()).num_days().abs();
+
+        phrase_names.format_relative(&unit_names, (target - self.current_time).num_seconds(), days)
+    }
+
+    /// Describe target time as a calendar phrase relative to current time, e.g.
+    /// "Yesterday at 14:00", "Last Friday at 9:30", "April 1" or "April 1, 2022"
+    pub fn to_calendar(&self, target: DateTime<FixedOffset>) -> String {
+        let mut phrase_names = PhraseNames::from_map(PhraseNames::get_defaults());
+        phrase_names.add_names(self.custom_phrases.to_owned());
+
+        let week_start_day = match self.first_weekday {
+            WeekStartDay::Monday => 1,
+            WeekStartDay::Sunday => 7,
+        };
+
+        fuzzy::to_calendar(&self.current_time, target, week_start_day, &phrase_names, &self.custom_tokens)
+    }
+
+    /// Convert source string into a datetime value the same way as `to_datetime`,
+    /// but describe the patterns that matched and the rules that were applied
+    pub fn explain(&self, source: &str) -> Explanation {
+        let custom_tokens = self.tokens();
+        let (pattern, tokens) = token::tokenize_pattern(&source, custom_tokens.to_owned());
+        let settings = self.settings();
+
+        let mut explanation = Explanation {
+            pattern: pattern.to_owned(),
+            steps: Vec::new(),
+            date_years: false,
+            reset_time: false,
+            week_start_mon: settings.week_start_mon,
+            result: Err(ParseError::NoMatchingPattern),
+        };
+
+        let result = fuzzy::convert(
+            &pattern,
+            tokens,
+            &self.current_time,
+            &settings,
+            self.custom_patterns.to_owned(),
+            self.local_zone,
+            Some(&mut explanation),
+        );
+
+        explanation.result = result.map_err(|e| with_unknown_token(e, source, &custom_tokens, &self.custom_patterns));
+        explanation
+    }
+
+    /// Settings for the conversion, as given by the caller
+    fn settings(&self) -> fuzzy::Settings {
+        fuzzy::Settings {
+            date_order: self.date_order,
+            day_periods: self.day_periods.to_owned(),
+            fiscal_start: self.fiscal_year_start.number_from_month() as i64,
+            holidays: self.holidays.to_owned(),
+            holiday_rules: self.holiday_rules.to_owned(),
+            two_digit_year: self.two_digit_year,
+            week_start_mon: self.first_weekday.eq(&WeekStartDay::Monday),
+            weekend: self.weekend,
+        }
+    }
+
+    /// Holiday names along with custom tokens, letting custom tokens
+    /// take precedence over holidays of the same name
+    fn tokens(&self) -> HashMap<String, Token> {
+        let mut tokens = self.holiday_rules.tokens();
+        tokens.extend(self.custom_tokens.to_owned());
+        tokens
     }
 }
 
@@ -65,6 +267,9 @@ pub struct FuzzyDuration {
     custom_units: HashMap<String, String>,
     max_unit: String,
     min_unit: String,
+    precision: usize,
+    rounding: Rounding,
+    sign_style: SignStyle,
     unit_group: UnitGroup,
 }
 
@@ -74,6 +279,9 @@ impl FuzzyDuration {
             custom_units: HashMap::new(),
             min_unit: String::new(),
             max_unit: String::new(),
+            precision: 0,
+            rounding: Rounding::default(),
+            sign_style: SignStyle::default(),
             unit_group: UnitGroup::Default,
         }
     }
@@ -98,23 +306,64 @@ impl FuzzyDuration {
         self
     }
 
-    /// Convert number of seconds into a time duration string
+    /// Set number of the most significant non-zero units to show, e.g. 2 for "1w 1d"
+    /// rather than "1w 1d 1hr 1min 1sec", with 0 for showing all units
+    pub fn set_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Set how the lowest unit shown is rounded when limiting the number of units
+    pub fn set_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Set how negative durations are shown, e.g. "-1hr" or "1hr ago"
+    pub fn set_sign_style(mut self, style: SignStyle) -> Self {
+        self.sign_style = style;
+        self
+    }
+
+    /// Convert number of seconds into a time duration string, in which
+    /// milliseconds and microseconds, e.g. "350ms", can be read back into
+    /// seconds only with compact durations enabled
     pub fn to_duration(&self, seconds: f64) -> String {
+        if self.unit_group.eq(&UnitGroup::Iso) {
+            return fuzzy::to_iso_duration(seconds, &self.max_unit, &self.min_unit);
+        }
+
         let mut unit_names = UnitNames::from_name(&self.unit_group);
         unit_names.add_names(self.custom_units.to_owned());
 
-        fuzzy::to_duration(seconds, &unit_names, &self.max_unit, &self.min_unit)
+        fuzzy::to_duration(
+            seconds,
+            &unit_names,
+            &self.max_unit,
+            &self.min_unit,
+            self.sign_style,
+            self.precision,
+            self.rounding,
+        )
     }
 }
 
 pub struct FuzzySeconds {
+    compact: bool,
     custom_patterns: HashMap<String, String>,
     custom_tokens: HashMap<String, Token>,
 }
 
 impl FuzzySeconds {
     pub fn new() -> Self {
-        Self { custom_patterns: HashMap::new(), custom_tokens: HashMap::new() }
+        Self { compact: false, custom_patterns: HashMap::new(), custom_tokens: HashMap::new() }
+    }
+
+    /// Read compact durations in the style of Go and systemd, e.g. "1h30m45s"
+    /// or "250ms", in which "m" means minutes rather than months
+    pub fn set_compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
     }
 
     pub fn set_custom_patterns(mut self, custom: HashMap<String, String>) -> Self {
@@ -127,32 +376,77 @@ impl FuzzySeconds {
         self
     }
 
-    /// Tokenize source string and then convert it seconds, reflecting exact duration
-    pub fn to_seconds(&self, source: &str) -> Result<f64, String> {
-        let (pattern, tokens) = token::tokenize(&source, self.custom_tokens.to_owned());
+    /// Tokenize source string and then convert it seconds, reflecting exact duration,
+    /// with ISO 8601 durations such as "PT1H30M" read as they are
+    pub fn to_seconds(&self, source: &str) -> Result<f64, ParseError> {
+        if let Some(result) = fuzzy::from_iso_duration(source.trim()) {
+            return result;
+        }
 
-        if !token::is_time_duration(&pattern) {
-            return Err(format!("Unable to convert \"{}\" into seconds", source));
+        let compact_result = self.compact.then(|| fuzzy::from_compact_duration(source.trim())).flatten();
+
+        if let Some(result) = compact_result {
+            return result;
         }
 
-        for token in &tokens {
-            if token.token.is_unit() && token.value.eq(&7) {
-                return Err(String::from("Converting years into seconds is not supported"));
-            }
+        let (pattern, tokens) = token::tokenize_pattern(&source, self.custom_tokens.to_owned());
+
+        if !token::is_time_duration(&pattern) {
+            let error = ParseError::NoMatchingPattern;
+            return Err(with_unknown_token(error, source, &self.custom_tokens, &self.custom_patterns));
+        }
 
-            if token.token.is_unit() && token.value.eq(&6) {
-                return Err(String::from("Converting months into seconds is not supported"));
+        for token in tokens.iter().filter(|v| v.token.is_unit()) {
+            if let Some(unit_name) = fuzzy::inexact_unit_name(token.value) {
+                return Err(ParseError::UnsupportedUnit { unit: unit_name });
             }
         }
 
         let current_time = Utc::now().fixed_offset();
 
-        if let Some(from_time) = fuzzy::convert(&pattern, tokens, &current_time, true, self.custom_patterns.to_owned())
-        {
-            let duration: Duration = from_time - current_time;
-            return Ok((duration.num_milliseconds() / 1_000) as f64);
-        }
+        let from_time = fuzzy::convert(
+            &pattern,
+            tokens,
+            &current_time,
+            &fuzzy::Settings {
+                date_order: DateOrder::default(),
+                day_periods: HashMap::new(),
+                fiscal_start: 1,
+                holidays: None,
+                holiday_rules: HolidayRules::new(),
+                two_digit_year: TwoDigitYear::default(),
+                week_start_mon: true,
+                weekend: WeekendDays::default(),
+            },
+            self.custom_patterns.to_owned(),
+            LocalZone::default(),
+            None,
+        )
+        .map_err(|e| with_unknown_token(e, source, &self.custom_tokens, &self.custom_patterns))?;
+
+        let duration: Duration = from_time - current_time;
+        Ok(duration.num_milliseconds() as f64 / 1_000.0)
+    }
+}
+
+/// Replace failure to match any pattern with a more specific error, when
+/// source string contains a word that is neither a token nor in any pattern
+fn with_unknown_token(
+    error: ParseError,
+    source: &str,
+    custom_tokens: &HashMap<String, Token>,
+    custom_patterns: &HashMap<String, String>,
+) -> ParseError {
+    if error.ne(&ParseError::NoMatchingPattern) {
+        return error;
+    }
+
+    let unknown = token::find_words(source, custom_tokens.to_owned())
+        .into_iter()
+        .find(|v| !Pattern::is_word(&source[v.to_owned()], custom_patterns));
 
-        Err(format!("Unable to convert \"{}\" into seconds", source))
+    match unknown {
+        Some(span) => ParseError::UnknownToken { span: span },
+        None => error,
     }
 }
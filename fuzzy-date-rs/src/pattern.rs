@@ -8,6 +8,19 @@ pub const PATTERN_MIDNIGHT: &'static str = "midnight";
 pub const PATTERN_YESTERDAY: &'static str = "yesterday";
 pub const PATTERN_TOMORROW: &'static str = "tomorrow";
 
+pub const PATTERN_NOON: &'static str = "noon";
+pub const PATTERN_MIDDAY: &'static str = "midday";
+pub const PATTERN_MORNING: &'static str = "morning";
+pub const PATTERN_THIS_MORNING: &'static str = "this morning";
+pub const PATTERN_AFTERNOON: &'static str = "afternoon";
+pub const PATTERN_THIS_AFTERNOON: &'static str = "this afternoon";
+pub const PATTERN_EVENING: &'static str = "evening";
+pub const PATTERN_THIS_EVENING: &'static str = "this evening";
+pub const PATTERN_TONIGHT: &'static str = "tonight";
+pub const PATTERN_EOD: &'static str = "eod";
+pub const PATTERN_END_OF_BUSINESS: &'static str = "end of business";
+pub const PATTERN_CLOSE_OF_BUSINESS: &'static str = "close of business";
+
 pub const PATTERN_WDAY: &'static str = "[wday]";
 pub const PATTERN_THIS_WDAY: &'static str = "this [wday]";
 pub const PATTERN_PREV_WDAY: &'static str = "prev [wday]";
@@ -38,6 +51,22 @@ pub const PATTERN_PLUS_SHORT_UNIT: &'static str = "+[int][short_unit]";
 pub const PATTERN_PLUS_LONG_UNIT: &'static str = "+[int] [long_unit]";
 pub const PATTERN_UNIT_AGO: &'static str = "[int] [unit] ago";
 pub const PATTERN_LONG_UNIT_AGO: &'static str = "[int] [long_unit] ago";
+pub const PATTERN_IN_UNIT: &'static str = "in [int] [unit]";
+pub const PATTERN_IN_SHORT_UNIT: &'static str = "in [int][short_unit]";
+pub const PATTERN_IN_LONG_UNIT: &'static str = "in [int] [long_unit]";
+pub const PATTERN_WITHIN_UNIT: &'static str = "within [int] [unit]";
+pub const PATTERN_WITHIN_SHORT_UNIT: &'static str = "within [int][short_unit]";
+pub const PATTERN_WITHIN_LONG_UNIT: &'static str = "within [int] [long_unit]";
+pub const PATTERN_UNIT_FROM_NOW: &'static str = "[int] [unit] from now";
+pub const PATTERN_SHORT_UNIT_FROM_NOW: &'static str = "[int][short_unit] from now";
+pub const PATTERN_LONG_UNIT_FROM_NOW: &'static str = "[int] [long_unit] from now";
+pub const PATTERN_UNIT_HENCE: &'static str = "[int] [unit] hence";
+pub const PATTERN_SHORT_UNIT_HENCE: &'static str = "[int][short_unit] hence";
+pub const PATTERN_LONG_UNIT_HENCE: &'static str = "[int] [long_unit] hence";
+pub const PATTERN_HALF_LONG_UNIT: &'static str = "half [int] [long_unit]";
+pub const PATTERN_HALF_LONG_UNIT_AGO: &'static str = "half [int] [long_unit] ago";
+pub const PATTERN_LONG_UNIT_AND_HALF: &'static str = "[int] [long_unit] and [int] half";
+pub const PATTERN_LONG_UNIT_AND_HALF_AGO: &'static str = "[int] [long_unit] and [int] half ago";
 pub const PATTERN_LONG_UNIT_INT: &'static str = "[long_unit] [int]";
 pub const PATTERN_LONG_UNIT_INT_YEAR: &'static str = "[long_unit] [int] [year]";
 
@@ -55,6 +84,44 @@ pub const PATTERN_LAST_WDAY_OF_MONTH: &'static str = "last [wday] of [month]";
 pub const PATTERN_LAST_WDAY_OF_MONTH_YEAR: &'static str = "last [wday] of [month] [year]";
 pub const PATTERN_LAST_WDAY_OF_YEAR: &'static str = "last [wday] of [year]";
 
+pub const PATTERN_FIRST_WDAY_OF_THIS_LONG_UNIT: &'static str = "first [wday] of this [long_unit]";
+pub const PATTERN_FIRST_WDAY_OF_PREV_LONG_UNIT: &'static str = "first [wday] of prev [long_unit]";
+pub const PATTERN_FIRST_WDAY_OF_LAST_LONG_UNIT: &'static str = "first [wday] of last [long_unit]";
+pub const PATTERN_FIRST_WDAY_OF_NEXT_LONG_UNIT: &'static str = "first [wday] of next [long_unit]";
+pub const PATTERN_LAST_WDAY_OF_THIS_LONG_UNIT: &'static str = "last [wday] of this [long_unit]";
+pub const PATTERN_LAST_WDAY_OF_PREV_LONG_UNIT: &'static str = "last [wday] of prev [long_unit]";
+pub const PATTERN_LAST_WDAY_OF_LAST_LONG_UNIT: &'static str = "last [wday] of last [long_unit]";
+pub const PATTERN_LAST_WDAY_OF_NEXT_LONG_UNIT: &'static str = "last [wday] of next [long_unit]";
+
+pub const PATTERN_NTH_WDAY_OF_MONTH: &'static str = "[nth] [wday] of [month]";
+pub const PATTERN_NTH_WDAY_OF_MONTH_YEAR: &'static str = "[nth] [wday] of [month] [year]";
+pub const PATTERN_NTH_WDAY_OF_YEAR: &'static str = "[nth] [wday] of [year]";
+pub const PATTERN_NTH_WDAY_OF_THIS_LONG_UNIT: &'static str = "[nth] [wday] of this [long_unit]";
+pub const PATTERN_NTH_WDAY_OF_PREV_LONG_UNIT: &'static str = "[nth] [wday] of prev [long_unit]";
+pub const PATTERN_NTH_WDAY_OF_LAST_LONG_UNIT: &'static str = "[nth] [wday] of last [long_unit]";
+pub const PATTERN_NTH_WDAY_OF_NEXT_LONG_UNIT: &'static str = "[nth] [wday] of next [long_unit]";
+pub const PATTERN_NTH_LAST_WDAY_OF_MONTH: &'static str = "[nth] to last [wday] of [month]";
+pub const PATTERN_NTH_LAST_WDAY_OF_MONTH_YEAR: &'static str = "[nth] to last [wday] of [month] [year]";
+pub const PATTERN_NTH_LAST_WDAY_OF_YEAR: &'static str = "[nth] to last [wday] of [year]";
+pub const PATTERN_NTH_LAST_WDAY_OF_THIS_LONG_UNIT: &'static str = "[nth] to last [wday] of this [long_unit]";
+pub const PATTERN_NTH_LAST_WDAY_OF_PREV_LONG_UNIT: &'static str = "[nth] to last [wday] of prev [long_unit]";
+pub const PATTERN_NTH_LAST_WDAY_OF_LAST_LONG_UNIT: &'static str = "[nth] to last [wday] of last [long_unit]";
+pub const PATTERN_NTH_LAST_WDAY_OF_NEXT_LONG_UNIT: &'static str = "[nth] to last [wday] of next [long_unit]";
+pub const PATTERN_THE_NTH_WDAY_OF_MONTH: &'static str = "the [nth] [wday] of [month]";
+pub const PATTERN_THE_NTH_WDAY_OF_MONTH_YEAR: &'static str = "the [nth] [wday] of [month] [year]";
+pub const PATTERN_THE_NTH_WDAY_OF_YEAR: &'static str = "the [nth] [wday] of [year]";
+pub const PATTERN_THE_NTH_WDAY_OF_THIS_LONG_UNIT: &'static str = "the [nth] [wday] of this [long_unit]";
+pub const PATTERN_THE_NTH_WDAY_OF_PREV_LONG_UNIT: &'static str = "the [nth] [wday] of prev [long_unit]";
+pub const PATTERN_THE_NTH_WDAY_OF_LAST_LONG_UNIT: &'static str = "the [nth] [wday] of last [long_unit]";
+pub const PATTERN_THE_NTH_WDAY_OF_NEXT_LONG_UNIT: &'static str = "the [nth] [wday] of next [long_unit]";
+pub const PATTERN_THE_NTH_LAST_WDAY_OF_MONTH: &'static str = "the [nth] to last [wday] of [month]";
+pub const PATTERN_THE_NTH_LAST_WDAY_OF_MONTH_YEAR: &'static str = "the [nth] to last [wday] of [month] [year]";
+pub const PATTERN_THE_NTH_LAST_WDAY_OF_YEAR: &'static str = "the [nth] to last [wday] of [year]";
+pub const PATTERN_THE_NTH_LAST_WDAY_OF_THIS_LONG_UNIT: &'static str = "the [nth] to last [wday] of this [long_unit]";
+pub const PATTERN_THE_NTH_LAST_WDAY_OF_PREV_LONG_UNIT: &'static str = "the [nth] to last [wday] of prev [long_unit]";
+pub const PATTERN_THE_NTH_LAST_WDAY_OF_LAST_LONG_UNIT: &'static str = "the [nth] to last [wday] of last [long_unit]";
+pub const PATTERN_THE_NTH_LAST_WDAY_OF_NEXT_LONG_UNIT: &'static str = "the [nth] to last [wday] of next [long_unit]";
+
 pub const PATTERN_FIRST_OF_LONG_UNIT: &'static str = "first of [long_unit]";
 pub const PATTERN_FIRST_OF_THE_LONG_UNIT: &'static str = "first of the [long_unit]";
 pub const PATTERN_FIRST_OF_THIS_LONG_UNIT: &'static str = "first of this [long_unit]";
@@ -63,7 +130,9 @@ pub const PATTERN_LAST_OF_THE_LONG_UNIT: &'static str = "last of the [long_unit]
 pub const PATTERN_LAST_OF_THIS_LONG_UNIT: &'static str = "last of this [long_unit]";
 
 pub const PATTERN_FIRST_LONG_UNIT_OF_THIS_LONG_UNIT: &'static str = "first [long_unit] of this [long_unit]";
+pub const PATTERN_FIRST_LONG_UNIT_OF_THE_LONG_UNIT: &'static str = "first [long_unit] of the [long_unit]";
 pub const PATTERN_LAST_LONG_UNIT_OF_THIS_LONG_UNIT: &'static str = "last [long_unit] of this [long_unit]";
+pub const PATTERN_LAST_LONG_UNIT_OF_THE_LONG_UNIT: &'static str = "last [long_unit] of the [long_unit]";
 pub const PATTERN_FIRST_LONG_UNIT_OF_PREV_LONG_UNIT: &'static str = "first [long_unit] of prev [long_unit]";
 pub const PATTERN_LAST_LONG_UNIT_OF_PREV_LONG_UNIT: &'static str = "last [long_unit] of prev [long_unit]";
 pub const PATTERN_FIRST_LONG_UNIT_OF_LAST_LONG_UNIT: &'static str = "first [long_unit] of last [long_unit]";
@@ -75,17 +144,68 @@ pub const PATTERN_INTEGER: &'static str = "[int]";
 pub const PATTERN_MONTH: &'static str = "[month]";
 pub const PATTERN_MONTH_YEAR: &'static str = "[month] [year]";
 
+pub const PATTERN_HOLIDAY: &'static str = "[holiday]";
+pub const PATTERN_HOLIDAY_YEAR: &'static str = "[holiday] [year]";
+pub const PATTERN_THIS_HOLIDAY: &'static str = "this [holiday]";
+pub const PATTERN_PREV_HOLIDAY: &'static str = "prev [holiday]";
+pub const PATTERN_LAST_HOLIDAY: &'static str = "last [holiday]";
+pub const PATTERN_NEXT_HOLIDAY: &'static str = "next [holiday]";
+pub const PATTERN_LONG_UNIT_BEFORE_HOLIDAY: &'static str = "[int] [long_unit] before [holiday]";
+pub const PATTERN_LONG_UNIT_BEFORE_HOLIDAY_YEAR: &'static str = "[int] [long_unit] before [holiday] [year]";
+pub const PATTERN_LONG_UNIT_AFTER_HOLIDAY: &'static str = "[int] [long_unit] after [holiday]";
+pub const PATTERN_LONG_UNIT_AFTER_HOLIDAY_YEAR: &'static str = "[int] [long_unit] after [holiday] [year]";
+
+pub const PATTERN_OFFSET: &'static str = "[offset]";
+
 pub const PATTERN_TIMESTAMP: &'static str = "[timestamp]";
 pub const PATTERN_TIMESTAMP_FLOAT: &'static str = "[timestamp].[int]";
 
 pub const PATTERN_YEAR: &'static str = "[year]";
 
+pub const PATTERN_ZONE: &'static str = "[zone]";
+
 pub const PATTERN_YEAR_WEEK: &'static str = "[year]-W[int]";
 pub const PATTERN_YW: &'static str = "[year]W[int]";
 
+pub const PATTERN_QUARTER: &'static str = "Q[int]";
+pub const PATTERN_QUARTER_YEAR: &'static str = "Q[int] [year]";
+pub const PATTERN_YEAR_QUARTER: &'static str = "[year] Q[int]";
+pub const PATTERN_YEAR_QUARTER_DASHED: &'static str = "[year]-Q[int]";
+
+pub const PATTERN_THIS_FISCAL_LONG_UNIT: &'static str = "this fiscal [long_unit]";
+pub const PATTERN_PREV_FISCAL_LONG_UNIT: &'static str = "prev fiscal [long_unit]";
+pub const PATTERN_LAST_FISCAL_LONG_UNIT: &'static str = "last fiscal [long_unit]";
+pub const PATTERN_NEXT_FISCAL_LONG_UNIT: &'static str = "next fiscal [long_unit]";
+pub const PATTERN_FISCAL_YEAR: &'static str = "FY[year]";
+pub const PATTERN_FISCAL_SHORT_YEAR: &'static str = "FY[int]";
+pub const PATTERN_QUARTER_FISCAL_YEAR: &'static str = "Q[int] FY[year]";
+pub const PATTERN_QUARTER_FISCAL_SHORT_YEAR: &'static str = "Q[int] FY[int]";
+
+pub const PATTERN_FIRST_LONG_UNIT_OF_FISCAL_YEAR: &'static str = "first [long_unit] of FY[year]";
+pub const PATTERN_FIRST_LONG_UNIT_OF_FISCAL_SHORT_YEAR: &'static str = "first [long_unit] of FY[int]";
+pub const PATTERN_LAST_LONG_UNIT_OF_FISCAL_YEAR: &'static str = "last [long_unit] of FY[year]";
+pub const PATTERN_LAST_LONG_UNIT_OF_FISCAL_SHORT_YEAR: &'static str = "last [long_unit] of FY[int]";
+
+pub const PATTERN_FIRST_LONG_UNIT_OF_THIS_FISCAL_LONG_UNIT: &'static str =
+    "first [long_unit] of this fiscal [long_unit]";
+pub const PATTERN_LAST_LONG_UNIT_OF_THIS_FISCAL_LONG_UNIT: &'static str = "last [long_unit] of this fiscal [long_unit]";
+pub const PATTERN_FIRST_LONG_UNIT_OF_PREV_FISCAL_LONG_UNIT: &'static str =
+    "first [long_unit] of prev fiscal [long_unit]";
+pub const PATTERN_LAST_LONG_UNIT_OF_PREV_FISCAL_LONG_UNIT: &'static str = "last [long_unit] of prev fiscal [long_unit]";
+pub const PATTERN_FIRST_LONG_UNIT_OF_LAST_FISCAL_LONG_UNIT: &'static str =
+    "first [long_unit] of last fiscal [long_unit]";
+pub const PATTERN_LAST_LONG_UNIT_OF_LAST_FISCAL_LONG_UNIT: &'static str = "last [long_unit] of last fiscal [long_unit]";
+pub const PATTERN_FIRST_LONG_UNIT_OF_NEXT_FISCAL_LONG_UNIT: &'static str =
+    "first [long_unit] of next fiscal [long_unit]";
+pub const PATTERN_LAST_LONG_UNIT_OF_NEXT_FISCAL_LONG_UNIT: &'static str = "last [long_unit] of next fiscal [long_unit]";
+
 pub const PATTERN_DATE_YMD: &'static str = "[year]-[int]-[int]";
 pub const PATTERN_DATE_DMY: &'static str = "[int].[int].[year]";
 pub const PATTERN_DATE_MDY: &'static str = "[int]/[int]/[year]";
+pub const PATTERN_DATE_DMY_DASHED: &'static str = "[int]-[int]-[year]";
+pub const PATTERN_DATE_DMY_SHORT: &'static str = "[int].[int].[int]";
+pub const PATTERN_DATE_MDY_SHORT: &'static str = "[int]/[int]/[int]";
+pub const PATTERN_DATE_DMY_SHORT_DASHED: &'static str = "[int]-[int]-[int]";
 
 pub const PATTERN_DATE_MONTH_DAY: &'static str = "[month] [int]";
 pub const PATTERN_DATE_MONTH_DAY_YEAR: &'static str = "[month] [int] [year]";
@@ -100,6 +220,10 @@ pub const PATTERN_DATE_NTH_MONTH_YEAR: &'static str = "[nth] [month] [year]";
 pub const PATTERN_DATE_NTH_OF_MONTH: &'static str = "[nth] of [month]";
 pub const PATTERN_DATE_NTH_OF_MONTH_YEAR: &'static str = "[nth] of [month] [year]";
 pub const PATTERN_DATE_YEAR_MONTH_DAY_DASHED: &'static str = "[year]-[month]-[int]";
+pub const PATTERN_DATE_MONTH_DAY_SHORT_YEAR: &'static str = "[month] [int] [int]";
+pub const PATTERN_DATE_MONTH_DAY_SHORT_YEAR_DASHED: &'static str = "[month]-[int]-[int]";
+pub const PATTERN_DATE_DAY_MONTH_SHORT_YEAR: &'static str = "[int] [month] [int]";
+pub const PATTERN_DATE_DAY_MONTH_SHORT_YEAR_DASHED: &'static str = "[int]-[month]-[int]";
 
 pub const PATTERN_DATE_WDAY_DAY_MONTH: &'static str = "[wday] [int] [month]";
 pub const PATTERN_DATE_WDAY_DAY_MONTH_YEAR: &'static str = "[wday] [int] [month] [year]";
@@ -175,6 +299,7 @@ pub const TOKEN_SHORT_UNIT_DAY: i16 = 404;
 pub const TOKEN_SHORT_UNIT_WEEK: i16 = 405;
 pub const TOKEN_SHORT_UNIT_MONTH: i16 = 406;
 pub const TOKEN_SHORT_UNIT_YEAR: i16 = 407;
+pub const TOKEN_SHORT_UNIT_QUARTER: i16 = 408;
 
 pub const TOKEN_LONG_UNIT_SEC: i16 = 501;
 pub const TOKEN_LONG_UNIT_MIN: i16 = 502;
@@ -183,33 +308,75 @@ pub const TOKEN_LONG_UNIT_DAY: i16 = 504;
 pub const TOKEN_LONG_UNIT_WEEK: i16 = 505;
 pub const TOKEN_LONG_UNIT_MONTH: i16 = 506;
 pub const TOKEN_LONG_UNIT_YEAR: i16 = 507;
+pub const TOKEN_LONG_UNIT_QUARTER: i16 = 508;
+pub const TOKEN_LONG_UNIT_WEEKEND: i16 = 509;
+pub const TOKEN_LONG_UNIT_BUSINESS_DAY: i16 = 510;
 
 pub const TOKEN_MERIDIEM_AM: i16 = 601;
 pub const TOKEN_MERIDIEM_PM: i16 = 602;
 
+pub const UNIT_AGO: &'static str = "ago";
 pub const UNIT_DAY: &'static str = "day";
 pub const UNIT_DAYS: &'static str = "days";
 pub const UNIT_HOUR: &'static str = "hour";
 pub const UNIT_HOURS: &'static str = "hours";
+pub const UNIT_IN: &'static str = "in";
+pub const UNIT_MICROSECOND: &'static str = "microsecond";
+pub const UNIT_MICROSECONDS: &'static str = "microseconds";
+pub const UNIT_MILLISECOND: &'static str = "millisecond";
+pub const UNIT_MILLISECONDS: &'static str = "milliseconds";
 pub const UNIT_MINUTE: &'static str = "minute";
 pub const UNIT_MINUTES: &'static str = "minutes";
+pub const UNIT_NEGATIVE_PREFIX: &'static str = "negative_prefix";
+pub const UNIT_NEGATIVE_SUFFIX: &'static str = "negative_suffix";
 pub const UNIT_SECOND: &'static str = "second";
 pub const UNIT_SECONDS: &'static str = "seconds";
 pub const UNIT_WEEK: &'static str = "week";
 pub const UNIT_WEEKS: &'static str = "weeks";
 
-#[derive(Clone, PartialEq, Eq, Hash)]
+pub const PHRASE_A_MINUTE: &'static str = "a_minute";
+pub const PHRASE_AT: &'static str = "at";
+pub const PHRASE_JUST_NOW: &'static str = "just_now";
+pub const PHRASE_LAST: &'static str = "last";
+pub const PHRASE_LAST_WEEK: &'static str = "last_week";
+pub const PHRASE_MONTH: &'static str = "month";
+pub const PHRASE_MONTHS: &'static str = "months";
+pub const PHRASE_NEXT: &'static str = "next";
+pub const PHRASE_NEXT_WEEK: &'static str = "next_week";
+pub const PHRASE_TODAY: &'static str = "today";
+pub const PHRASE_TOMORROW: &'static str = "tomorrow";
+pub const PHRASE_YEAR: &'static str = "year";
+pub const PHRASE_YEARS: &'static str = "years";
+pub const PHRASE_YESTERDAY: &'static str = "yesterday";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Pattern {
     Integer,
     Month,
     MonthYear,
 
+    Holiday,
+    HolidayYear,
+    PrevHoliday,
+    NextHoliday,
+    UnitBeforeHoliday,
+    UnitBeforeHolidayYear,
+    UnitAfterHoliday,
+    UnitAfterHolidayYear,
+
     Now,
     Today,
     Midnight,
     Yesterday,
     Tomorrow,
 
+    Morning,
+    Noon,
+    Afternoon,
+    Evening,
+    Tonight,
+    EndOfDay,
+
     Wday,
     ThisWday,
     PrevWday,
@@ -229,6 +396,10 @@ pub enum Pattern {
     PlusUnit,
 
     UnitAgo,
+    HalfUnit,
+    HalfUnitAgo,
+    UnitAndHalf,
+    UnitAndHalfAgo,
     UnitInt,
     UnitIntYear,
 
@@ -243,6 +414,9 @@ pub enum Pattern {
     FirstWdayOfMonth,
     FirstWdayOfMonthYear,
     FirstWdayOfYear,
+    FirstWdayOfThisUnit,
+    FirstWdayOfPrevUnit,
+    FirstWdayOfNextUnit,
 
     LastOfUnit,
     LastUnitOfMonth,
@@ -255,6 +429,25 @@ pub enum Pattern {
     LastWdayOfMonth,
     LastWdayOfMonthYear,
     LastWdayOfYear,
+    LastWdayOfThisUnit,
+    LastWdayOfPrevUnit,
+    LastWdayOfNextUnit,
+
+    NthWdayOfMonth,
+    NthWdayOfMonthYear,
+    NthWdayOfYear,
+    NthWdayOfThisUnit,
+    NthWdayOfPrevUnit,
+    NthWdayOfNextUnit,
+
+    NthLastWdayOfMonth,
+    NthLastWdayOfMonthYear,
+    NthLastWdayOfYear,
+    NthLastWdayOfThisUnit,
+    NthLastWdayOfPrevUnit,
+    NthLastWdayOfNextUnit,
+
+    Offset,
 
     Timestamp,
     TimestampFloat,
@@ -262,15 +455,40 @@ pub enum Pattern {
     Year,
     YearWeek,
 
+    Quarter,
+    QuarterYear,
+    YearQuarter,
+
+    ThisFiscalUnit,
+    PrevFiscalUnit,
+    NextFiscalUnit,
+    FiscalYear,
+    QuarterFiscalYear,
+
+    FirstUnitOfFiscalYear,
+    FirstUnitOfThisFiscalUnit,
+    FirstUnitOfPrevFiscalUnit,
+    FirstUnitOfNextFiscalUnit,
+    LastUnitOfFiscalYear,
+    LastUnitOfThisFiscalUnit,
+    LastUnitOfPrevFiscalUnit,
+    LastUnitOfNextFiscalUnit,
+
+    Zone,
+
     DateYmd,
     DateDmy,
     DateMdy,
+    DateDmyShort,
+    DateMdyShort,
     DateMonthDayYear,
+    DateMonthDayShortYear,
     DateMonthDay,
     DateMonthNth,
     DateMonthNthYear,
     DateDayMonth,
     DateDayMonthYear,
+    DateDayMonthShortYear,
     DateTimeYmdHms,
     DateTimeYmdHmsMs,
 
@@ -287,13 +505,19 @@ pub enum Pattern {
 }
 
 impl Pattern {
-    pub(crate) fn time_of_days() -> [Self; 5] {
+    pub(crate) fn time_of_days() -> [Self; 11] {
         [
             Self::TimeHm,
             Self::TimeHms,
             Self::TimeHmsMs,
             Self::TimeMeridiemH,
             Self::TimeMeridiemHm,
+            Self::Morning,
+            Self::Noon,
+            Self::Afternoon,
+            Self::Evening,
+            Self::Tonight,
+            Self::EndOfDay,
         ]
     }
 
@@ -312,21 +536,58 @@ impl Pattern {
         patterns().iter().filter(|&v| v.0.eq(&key)).map(|v| v.1).collect()
     }
 
+    /// Check that word appears in any of the patterns, e.g. "ago" or "next"
+    pub(crate) fn is_word(value: &str, custom: &HashMap<String, String>) -> bool {
+        let value = value.to_lowercase();
+
+        patterns()
+            .iter()
+            .map(|v| v.1.to_string())
+            .chain(custom.keys().map(|v| v.to_lowercase()))
+            .any(|v| {
+                v.split("[")
+                    .map(|part| part.split_once("]").map(|p| p.1).unwrap_or(part))
+                    .any(|part| part.split(" ").any(|word| word.eq(&value)))
+            })
+    }
+
     pub fn is_valid(value: &str) -> bool {
         patterns().iter().find(|&v| v.1 == value).is_some()
     }
 }
 
-fn patterns() -> [(Pattern, &'static str); 114] {
+fn patterns() -> [(Pattern, &'static str); 224] {
     [
         (Pattern::Integer, PATTERN_INTEGER),
         (Pattern::Month, PATTERN_MONTH),
         (Pattern::MonthYear, PATTERN_MONTH_YEAR),
+        (Pattern::Holiday, PATTERN_HOLIDAY),
+        (Pattern::HolidayYear, PATTERN_HOLIDAY_YEAR),
+        (Pattern::Holiday, PATTERN_THIS_HOLIDAY),
+        (Pattern::PrevHoliday, PATTERN_PREV_HOLIDAY),
+        (Pattern::PrevHoliday, PATTERN_LAST_HOLIDAY),
+        (Pattern::NextHoliday, PATTERN_NEXT_HOLIDAY),
+        (Pattern::UnitBeforeHoliday, PATTERN_LONG_UNIT_BEFORE_HOLIDAY),
+        (Pattern::UnitBeforeHolidayYear, PATTERN_LONG_UNIT_BEFORE_HOLIDAY_YEAR),
+        (Pattern::UnitAfterHoliday, PATTERN_LONG_UNIT_AFTER_HOLIDAY),
+        (Pattern::UnitAfterHolidayYear, PATTERN_LONG_UNIT_AFTER_HOLIDAY_YEAR),
         (Pattern::Now, PATTERN_NOW),
         (Pattern::Today, PATTERN_TODAY),
         (Pattern::Midnight, PATTERN_MIDNIGHT),
         (Pattern::Yesterday, PATTERN_YESTERDAY),
         (Pattern::Tomorrow, PATTERN_TOMORROW),
+        (Pattern::Noon, PATTERN_NOON),
+        (Pattern::Noon, PATTERN_MIDDAY),
+        (Pattern::Morning, PATTERN_MORNING),
+        (Pattern::Morning, PATTERN_THIS_MORNING),
+        (Pattern::Afternoon, PATTERN_AFTERNOON),
+        (Pattern::Afternoon, PATTERN_THIS_AFTERNOON),
+        (Pattern::Evening, PATTERN_EVENING),
+        (Pattern::Evening, PATTERN_THIS_EVENING),
+        (Pattern::Tonight, PATTERN_TONIGHT),
+        (Pattern::EndOfDay, PATTERN_EOD),
+        (Pattern::EndOfDay, PATTERN_END_OF_BUSINESS),
+        (Pattern::EndOfDay, PATTERN_CLOSE_OF_BUSINESS),
         (Pattern::Wday, PATTERN_WDAY),
         (Pattern::ThisWday, PATTERN_THIS_WDAY),
         (Pattern::PrevWday, PATTERN_PREV_WDAY),
@@ -350,8 +611,24 @@ fn patterns() -> [(Pattern, &'static str); 114] {
         (Pattern::PlusUnit, PATTERN_PLUS_UNIT),
         (Pattern::PlusUnit, PATTERN_PLUS_SHORT_UNIT),
         (Pattern::PlusUnit, PATTERN_PLUS_LONG_UNIT),
+        (Pattern::PlusUnit, PATTERN_IN_UNIT),
+        (Pattern::PlusUnit, PATTERN_IN_SHORT_UNIT),
+        (Pattern::PlusUnit, PATTERN_IN_LONG_UNIT),
+        (Pattern::PlusUnit, PATTERN_WITHIN_UNIT),
+        (Pattern::PlusUnit, PATTERN_WITHIN_SHORT_UNIT),
+        (Pattern::PlusUnit, PATTERN_WITHIN_LONG_UNIT),
+        (Pattern::PlusUnit, PATTERN_UNIT_FROM_NOW),
+        (Pattern::PlusUnit, PATTERN_SHORT_UNIT_FROM_NOW),
+        (Pattern::PlusUnit, PATTERN_LONG_UNIT_FROM_NOW),
+        (Pattern::PlusUnit, PATTERN_UNIT_HENCE),
+        (Pattern::PlusUnit, PATTERN_SHORT_UNIT_HENCE),
+        (Pattern::PlusUnit, PATTERN_LONG_UNIT_HENCE),
         (Pattern::UnitAgo, PATTERN_UNIT_AGO),
         (Pattern::UnitAgo, PATTERN_LONG_UNIT_AGO),
+        (Pattern::HalfUnit, PATTERN_HALF_LONG_UNIT),
+        (Pattern::HalfUnitAgo, PATTERN_HALF_LONG_UNIT_AGO),
+        (Pattern::UnitAndHalf, PATTERN_LONG_UNIT_AND_HALF),
+        (Pattern::UnitAndHalfAgo, PATTERN_LONG_UNIT_AND_HALF_AGO),
         (Pattern::UnitInt, PATTERN_LONG_UNIT_INT),
         (Pattern::UnitIntYear, PATTERN_LONG_UNIT_INT_YEAR),
         (Pattern::FirstOfUnit, PATTERN_FIRST_OF_LONG_UNIT),
@@ -361,6 +638,7 @@ fn patterns() -> [(Pattern, &'static str); 114] {
         (Pattern::FirstUnitOfMonthYear, PATTERN_FIRST_LONG_UNIT_OF_MONTH_YEAR),
         (Pattern::FirstUnitOfYear, PATTERN_FIRST_LONG_UNIT_OF_YEAR),
         (Pattern::FirstUnitOfThisUnit, PATTERN_FIRST_LONG_UNIT_OF_THIS_LONG_UNIT),
+        (Pattern::FirstUnitOfThisUnit, PATTERN_FIRST_LONG_UNIT_OF_THE_LONG_UNIT),
         (Pattern::FirstUnitOfPrevUnit, PATTERN_FIRST_LONG_UNIT_OF_PREV_LONG_UNIT),
         (Pattern::FirstUnitOfPrevUnit, PATTERN_FIRST_LONG_UNIT_OF_LAST_LONG_UNIT),
         (Pattern::FirstUnitOfNextUnit, PATTERN_FIRST_LONG_UNIT_OF_NEXT_LONG_UNIT),
@@ -370,6 +648,42 @@ fn patterns() -> [(Pattern, &'static str); 114] {
         (Pattern::LastWdayOfMonth, PATTERN_LAST_WDAY_OF_MONTH),
         (Pattern::LastWdayOfMonthYear, PATTERN_LAST_WDAY_OF_MONTH_YEAR),
         (Pattern::LastWdayOfYear, PATTERN_LAST_WDAY_OF_YEAR),
+        (Pattern::FirstWdayOfThisUnit, PATTERN_FIRST_WDAY_OF_THIS_LONG_UNIT),
+        (Pattern::FirstWdayOfPrevUnit, PATTERN_FIRST_WDAY_OF_PREV_LONG_UNIT),
+        (Pattern::FirstWdayOfPrevUnit, PATTERN_FIRST_WDAY_OF_LAST_LONG_UNIT),
+        (Pattern::FirstWdayOfNextUnit, PATTERN_FIRST_WDAY_OF_NEXT_LONG_UNIT),
+        (Pattern::LastWdayOfThisUnit, PATTERN_LAST_WDAY_OF_THIS_LONG_UNIT),
+        (Pattern::LastWdayOfPrevUnit, PATTERN_LAST_WDAY_OF_PREV_LONG_UNIT),
+        (Pattern::LastWdayOfPrevUnit, PATTERN_LAST_WDAY_OF_LAST_LONG_UNIT),
+        (Pattern::LastWdayOfNextUnit, PATTERN_LAST_WDAY_OF_NEXT_LONG_UNIT),
+        (Pattern::NthWdayOfMonth, PATTERN_NTH_WDAY_OF_MONTH),
+        (Pattern::NthWdayOfMonthYear, PATTERN_NTH_WDAY_OF_MONTH_YEAR),
+        (Pattern::NthWdayOfYear, PATTERN_NTH_WDAY_OF_YEAR),
+        (Pattern::NthWdayOfThisUnit, PATTERN_NTH_WDAY_OF_THIS_LONG_UNIT),
+        (Pattern::NthWdayOfPrevUnit, PATTERN_NTH_WDAY_OF_PREV_LONG_UNIT),
+        (Pattern::NthWdayOfPrevUnit, PATTERN_NTH_WDAY_OF_LAST_LONG_UNIT),
+        (Pattern::NthWdayOfNextUnit, PATTERN_NTH_WDAY_OF_NEXT_LONG_UNIT),
+        (Pattern::NthLastWdayOfMonth, PATTERN_NTH_LAST_WDAY_OF_MONTH),
+        (Pattern::NthLastWdayOfMonthYear, PATTERN_NTH_LAST_WDAY_OF_MONTH_YEAR),
+        (Pattern::NthLastWdayOfYear, PATTERN_NTH_LAST_WDAY_OF_YEAR),
+        (Pattern::NthLastWdayOfThisUnit, PATTERN_NTH_LAST_WDAY_OF_THIS_LONG_UNIT),
+        (Pattern::NthLastWdayOfPrevUnit, PATTERN_NTH_LAST_WDAY_OF_PREV_LONG_UNIT),
+        (Pattern::NthLastWdayOfPrevUnit, PATTERN_NTH_LAST_WDAY_OF_LAST_LONG_UNIT),
+        (Pattern::NthLastWdayOfNextUnit, PATTERN_NTH_LAST_WDAY_OF_NEXT_LONG_UNIT),
+        (Pattern::NthWdayOfMonth, PATTERN_THE_NTH_WDAY_OF_MONTH),
+        (Pattern::NthWdayOfMonthYear, PATTERN_THE_NTH_WDAY_OF_MONTH_YEAR),
+        (Pattern::NthWdayOfYear, PATTERN_THE_NTH_WDAY_OF_YEAR),
+        (Pattern::NthWdayOfThisUnit, PATTERN_THE_NTH_WDAY_OF_THIS_LONG_UNIT),
+        (Pattern::NthWdayOfPrevUnit, PATTERN_THE_NTH_WDAY_OF_PREV_LONG_UNIT),
+        (Pattern::NthWdayOfPrevUnit, PATTERN_THE_NTH_WDAY_OF_LAST_LONG_UNIT),
+        (Pattern::NthWdayOfNextUnit, PATTERN_THE_NTH_WDAY_OF_NEXT_LONG_UNIT),
+        (Pattern::NthLastWdayOfMonth, PATTERN_THE_NTH_LAST_WDAY_OF_MONTH),
+        (Pattern::NthLastWdayOfMonthYear, PATTERN_THE_NTH_LAST_WDAY_OF_MONTH_YEAR),
+        (Pattern::NthLastWdayOfYear, PATTERN_THE_NTH_LAST_WDAY_OF_YEAR),
+        (Pattern::NthLastWdayOfThisUnit, PATTERN_THE_NTH_LAST_WDAY_OF_THIS_LONG_UNIT),
+        (Pattern::NthLastWdayOfPrevUnit, PATTERN_THE_NTH_LAST_WDAY_OF_PREV_LONG_UNIT),
+        (Pattern::NthLastWdayOfPrevUnit, PATTERN_THE_NTH_LAST_WDAY_OF_LAST_LONG_UNIT),
+        (Pattern::NthLastWdayOfNextUnit, PATTERN_THE_NTH_LAST_WDAY_OF_NEXT_LONG_UNIT),
         (Pattern::LastOfUnit, PATTERN_LAST_OF_LONG_UNIT),
         (Pattern::LastOfUnit, PATTERN_LAST_OF_THE_LONG_UNIT),
         (Pattern::LastOfUnit, PATTERN_LAST_OF_THIS_LONG_UNIT),
@@ -377,26 +691,61 @@ fn patterns() -> [(Pattern, &'static str); 114] {
         (Pattern::LastUnitOfYear, PATTERN_LAST_LONG_UNIT_OF_YEAR),
         (Pattern::LastUnitOfMonthYear, PATTERN_LAST_LONG_UNIT_OF_MONTH_YEAR),
         (Pattern::LastUnitOfThisUnit, PATTERN_LAST_LONG_UNIT_OF_THIS_LONG_UNIT),
+        (Pattern::LastUnitOfThisUnit, PATTERN_LAST_LONG_UNIT_OF_THE_LONG_UNIT),
         (Pattern::LastUnitOfPrevUnit, PATTERN_LAST_LONG_UNIT_OF_PREV_LONG_UNIT),
         (Pattern::LastUnitOfPrevUnit, PATTERN_LAST_LONG_UNIT_OF_LAST_LONG_UNIT),
         (Pattern::LastUnitOfNextUnit, PATTERN_LAST_LONG_UNIT_OF_NEXT_LONG_UNIT),
+        (Pattern::Offset, PATTERN_OFFSET),
         (Pattern::Timestamp, PATTERN_TIMESTAMP),
         (Pattern::TimestampFloat, PATTERN_TIMESTAMP_FLOAT),
         (Pattern::Year, PATTERN_YEAR),
         (Pattern::YearWeek, PATTERN_YW),
         (Pattern::YearWeek, PATTERN_YEAR_WEEK),
+        (Pattern::Quarter, PATTERN_QUARTER),
+        (Pattern::QuarterYear, PATTERN_QUARTER_YEAR),
+        (Pattern::YearQuarter, PATTERN_YEAR_QUARTER),
+        (Pattern::YearQuarter, PATTERN_YEAR_QUARTER_DASHED),
+        (Pattern::ThisFiscalUnit, PATTERN_THIS_FISCAL_LONG_UNIT),
+        (Pattern::PrevFiscalUnit, PATTERN_PREV_FISCAL_LONG_UNIT),
+        (Pattern::PrevFiscalUnit, PATTERN_LAST_FISCAL_LONG_UNIT),
+        (Pattern::NextFiscalUnit, PATTERN_NEXT_FISCAL_LONG_UNIT),
+        (Pattern::FiscalYear, PATTERN_FISCAL_YEAR),
+        (Pattern::FiscalYear, PATTERN_FISCAL_SHORT_YEAR),
+        (Pattern::QuarterFiscalYear, PATTERN_QUARTER_FISCAL_YEAR),
+        (Pattern::QuarterFiscalYear, PATTERN_QUARTER_FISCAL_SHORT_YEAR),
+        (Pattern::FirstUnitOfFiscalYear, PATTERN_FIRST_LONG_UNIT_OF_FISCAL_YEAR),
+        (Pattern::FirstUnitOfFiscalYear, PATTERN_FIRST_LONG_UNIT_OF_FISCAL_SHORT_YEAR),
+        (Pattern::LastUnitOfFiscalYear, PATTERN_LAST_LONG_UNIT_OF_FISCAL_YEAR),
+        (Pattern::LastUnitOfFiscalYear, PATTERN_LAST_LONG_UNIT_OF_FISCAL_SHORT_YEAR),
+        (Pattern::FirstUnitOfThisFiscalUnit, PATTERN_FIRST_LONG_UNIT_OF_THIS_FISCAL_LONG_UNIT),
+        (Pattern::FirstUnitOfPrevFiscalUnit, PATTERN_FIRST_LONG_UNIT_OF_PREV_FISCAL_LONG_UNIT),
+        (Pattern::FirstUnitOfPrevFiscalUnit, PATTERN_FIRST_LONG_UNIT_OF_LAST_FISCAL_LONG_UNIT),
+        (Pattern::FirstUnitOfNextFiscalUnit, PATTERN_FIRST_LONG_UNIT_OF_NEXT_FISCAL_LONG_UNIT),
+        (Pattern::LastUnitOfThisFiscalUnit, PATTERN_LAST_LONG_UNIT_OF_THIS_FISCAL_LONG_UNIT),
+        (Pattern::LastUnitOfPrevFiscalUnit, PATTERN_LAST_LONG_UNIT_OF_PREV_FISCAL_LONG_UNIT),
+        (Pattern::LastUnitOfPrevFiscalUnit, PATTERN_LAST_LONG_UNIT_OF_LAST_FISCAL_LONG_UNIT),
+        (Pattern::LastUnitOfNextFiscalUnit, PATTERN_LAST_LONG_UNIT_OF_NEXT_FISCAL_LONG_UNIT),
+        (Pattern::Zone, PATTERN_ZONE),
         (Pattern::DateYmd, PATTERN_DATE_YMD),
         (Pattern::DateYmd, PATTERN_DATE_YEAR_MONTH_DAY_DASHED),
         (Pattern::DateDmy, PATTERN_DATE_DMY),
         (Pattern::DateMdy, PATTERN_DATE_MDY),
+        (Pattern::DateDmy, PATTERN_DATE_DMY_DASHED),
+        (Pattern::DateDmyShort, PATTERN_DATE_DMY_SHORT),
+        (Pattern::DateMdyShort, PATTERN_DATE_MDY_SHORT),
+        (Pattern::DateDmyShort, PATTERN_DATE_DMY_SHORT_DASHED),
         (Pattern::DateMonthDay, PATTERN_DATE_MONTH_DAY),
         (Pattern::DateMonthDayYear, PATTERN_DATE_MONTH_DAY_YEAR),
         (Pattern::DateMonthDayYear, PATTERN_DATE_MONTH_DAY_YEAR_DASHED),
+        (Pattern::DateMonthDayShortYear, PATTERN_DATE_MONTH_DAY_SHORT_YEAR),
+        (Pattern::DateMonthDayShortYear, PATTERN_DATE_MONTH_DAY_SHORT_YEAR_DASHED),
         (Pattern::DateMonthNth, PATTERN_DATE_MONTH_NTH),
         (Pattern::DateMonthNthYear, PATTERN_DATE_MONTH_NTH_YEAR),
         (Pattern::DateDayMonth, PATTERN_DATE_DAY_MONTH),
         (Pattern::DateDayMonthYear, PATTERN_DATE_DAY_MONTH_YEAR),
         (Pattern::DateDayMonthYear, PATTERN_DATE_DAY_MONTH_YEAR_DASHED),
+        (Pattern::DateDayMonthShortYear, PATTERN_DATE_DAY_MONTH_SHORT_YEAR),
+        (Pattern::DateDayMonthShortYear, PATTERN_DATE_DAY_MONTH_SHORT_YEAR_DASHED),
         (Pattern::DateDayMonth, PATTERN_DATE_NTH_MONTH),
         (Pattern::DateDayMonthYear, PATTERN_DATE_NTH_MONTH_YEAR),
         (Pattern::DateDayMonth, PATTERN_DATE_NTH_OF_MONTH),
@@ -1,4 +1,7 @@
+use chrono::Weekday;
 use std::collections::HashMap;
+use std::ops::Range;
+use std::str::FromStr;
 
 // Boundary characters that always trigger treating
 // parsing collected characters into token(s)
@@ -11,11 +14,30 @@ const CONDITIONAL_CHARS: [&'static str; 3] = [".", "T", "W"];
 // Characters that get muted from the pattern string
 const IGNORED_CHARS: [&'static str; 1] = [","];
 
+// Words that are accepted in durations, e.g. "an hour and a half"
+const DURATION_WORDS: [&'static str; 2] = ["and", "half"];
+
 // Prefix characters before numbers that mean the value should
 // be treated as a timestamp
 const PREFIX_CHARS_TIMESTAMP: [&'static str; 1] = ["@"];
 
-const STANDARD_TOKENS: [(&'static str, Token); 141] = [
+// Prefix characters before two digits that mean the value should
+// be treated as a year, e.g. "'23"
+const PREFIX_CHARS_YEAR: [&'static str; 2] = ["'", "’"];
+
+// Prefix words before numbers that are kept in the pattern as-is,
+// e.g. "Q1" for quarters and "FY2025" for fiscal years
+const PREFIX_WORDS: [&'static str; 2] = ["Q", "FY"];
+
+// Words before days that turn them into business days,
+// e.g. "3 business days" or "next working day"
+const BUSINESS_DAY_WORDS: [&'static str; 2] = ["business", "working"];
+
+// Decimal token values are stored as billionths of the number,
+// e.g. "1.5" is stored as 1_500_000_000
+pub(crate) const DECIMAL_SCALE: i64 = 1_000_000_000;
+
+const STANDARD_TOKENS: [(&'static str, Token); 217] = [
     // Months, abbreviated
     ("jan", Token { token: TokenType::Month, value: 1, zeros: 0 }),
     ("jan.", Token { token: TokenType::Month, value: 1, zeros: 0 }),
@@ -132,6 +154,41 @@ const STANDARD_TOKENS: [(&'static str, Token); 141] = [
     ("29th", Token { token: TokenType::Nth, value: 29, zeros: 0 }),
     ("30th", Token { token: TokenType::Nth, value: 30, zeros: 0 }),
     ("31st", Token { token: TokenType::Nth, value: 31, zeros: 0 }),
+    // Nth, spelled out
+    ("third", Token { token: TokenType::Nth, value: 3, zeros: 0 }),
+    ("fourth", Token { token: TokenType::Nth, value: 4, zeros: 0 }),
+    ("fifth", Token { token: TokenType::Nth, value: 5, zeros: 0 }),
+    // Numbers, spelled out
+    ("a", Token { token: TokenType::Integer, value: 1, zeros: 0 }),
+    ("an", Token { token: TokenType::Integer, value: 1, zeros: 0 }),
+    ("zero", Token { token: TokenType::Integer, value: 0, zeros: 0 }),
+    ("one", Token { token: TokenType::Integer, value: 1, zeros: 0 }),
+    ("two", Token { token: TokenType::Integer, value: 2, zeros: 0 }),
+    ("three", Token { token: TokenType::Integer, value: 3, zeros: 0 }),
+    ("four", Token { token: TokenType::Integer, value: 4, zeros: 0 }),
+    ("five", Token { token: TokenType::Integer, value: 5, zeros: 0 }),
+    ("six", Token { token: TokenType::Integer, value: 6, zeros: 0 }),
+    ("seven", Token { token: TokenType::Integer, value: 7, zeros: 0 }),
+    ("eight", Token { token: TokenType::Integer, value: 8, zeros: 0 }),
+    ("nine", Token { token: TokenType::Integer, value: 9, zeros: 0 }),
+    ("ten", Token { token: TokenType::Integer, value: 10, zeros: 0 }),
+    ("eleven", Token { token: TokenType::Integer, value: 11, zeros: 0 }),
+    ("twelve", Token { token: TokenType::Integer, value: 12, zeros: 0 }),
+    ("thirteen", Token { token: TokenType::Integer, value: 13, zeros: 0 }),
+    ("fourteen", Token { token: TokenType::Integer, value: 14, zeros: 0 }),
+    ("fifteen", Token { token: TokenType::Integer, value: 15, zeros: 0 }),
+    ("sixteen", Token { token: TokenType::Integer, value: 16, zeros: 0 }),
+    ("seventeen", Token { token: TokenType::Integer, value: 17, zeros: 0 }),
+    ("eighteen", Token { token: TokenType::Integer, value: 18, zeros: 0 }),
+    ("nineteen", Token { token: TokenType::Integer, value: 19, zeros: 0 }),
+    ("twenty", Token { token: TokenType::Integer, value: 20, zeros: 0 }),
+    ("thirty", Token { token: TokenType::Integer, value: 30, zeros: 0 }),
+    ("forty", Token { token: TokenType::Integer, value: 40, zeros: 0 }),
+    ("fifty", Token { token: TokenType::Integer, value: 50, zeros: 0 }),
+    ("sixty", Token { token: TokenType::Integer, value: 60, zeros: 0 }),
+    ("seventy", Token { token: TokenType::Integer, value: 70, zeros: 0 }),
+    ("eighty", Token { token: TokenType::Integer, value: 80, zeros: 0 }),
+    ("ninety", Token { token: TokenType::Integer, value: 90, zeros: 0 }),
     // Time units
     ("sec", Token { token: TokenType::Unit, value: 1, zeros: 0 }),
     ("min", Token { token: TokenType::Unit, value: 2, zeros: 0 }),
@@ -145,6 +202,7 @@ const STANDARD_TOKENS: [(&'static str, Token); 141] = [
     ("w", Token { token: TokenType::ShortUnit, value: 5, zeros: 0 }),
     ("m", Token { token: TokenType::ShortUnit, value: 6, zeros: 0 }),
     ("y", Token { token: TokenType::ShortUnit, value: 7, zeros: 0 }),
+    ("q", Token { token: TokenType::ShortUnit, value: 8, zeros: 0 }),
     // Long time units
     ("second", Token { token: TokenType::LongUnit, value: 1, zeros: 0 }),
     ("seconds", Token { token: TokenType::LongUnit, value: 1, zeros: 0 }),
@@ -160,13 +218,95 @@ const STANDARD_TOKENS: [(&'static str, Token); 141] = [
     ("months", Token { token: TokenType::LongUnit, value: 6, zeros: 0 }),
     ("year", Token { token: TokenType::LongUnit, value: 7, zeros: 0 }),
     ("years", Token { token: TokenType::LongUnit, value: 7, zeros: 0 }),
+    ("quarter", Token { token: TokenType::LongUnit, value: 8, zeros: 0 }),
+    ("quarters", Token { token: TokenType::LongUnit, value: 8, zeros: 0 }),
+    ("weekend", Token { token: TokenType::LongUnit, value: 9, zeros: 0 }),
+    ("weekends", Token { token: TokenType::LongUnit, value: 9, zeros: 0 }),
     // Meridiems
     ("am", Token { token: TokenType::Meridiem, value: 1, zeros: 0 }),
     ("a.m.", Token { token: TokenType::Meridiem, value: 1, zeros: 0 }),
     ("pm", Token { token: TokenType::Meridiem, value: 2, zeros: 0 }),
     ("p.m.", Token { token: TokenType::Meridiem, value: 2, zeros: 0 }),
+    // UTC offsets
+    ("z", Token { token: TokenType::Offset, value: 0, zeros: 0 }),
+    // Time zone abbreviations, as UTC offsets, leaving out ones that
+    // are also common words, e.g. "wet" and "west"
+    ("utc", Token { token: TokenType::Zone, value: 0, zeros: 0 }),
+    ("gmt", Token { token: TokenType::Zone, value: 0, zeros: 0 }),
+    ("bst", Token { token: TokenType::Zone, value: 3600, zeros: 0 }),
+    ("cet", Token { token: TokenType::Zone, value: 3600, zeros: 0 }),
+    ("cest", Token { token: TokenType::Zone, value: 7200, zeros: 0 }),
+    ("eet", Token { token: TokenType::Zone, value: 7200, zeros: 0 }),
+    ("eest", Token { token: TokenType::Zone, value: 10800, zeros: 0 }),
+    ("msk", Token { token: TokenType::Zone, value: 10800, zeros: 0 }),
+    ("ist", Token { token: TokenType::Zone, value: 19800, zeros: 0 }),
+    ("pkt", Token { token: TokenType::Zone, value: 18000, zeros: 0 }),
+    ("ict", Token { token: TokenType::Zone, value: 25200, zeros: 0 }),
+    ("sgt", Token { token: TokenType::Zone, value: 28800, zeros: 0 }),
+    ("hkt", Token { token: TokenType::Zone, value: 28800, zeros: 0 }),
+    ("awst", Token { token: TokenType::Zone, value: 28800, zeros: 0 }),
+    ("jst", Token { token: TokenType::Zone, value: 32400, zeros: 0 }),
+    ("kst", Token { token: TokenType::Zone, value: 32400, zeros: 0 }),
+    ("acst", Token { token: TokenType::Zone, value: 34200, zeros: 0 }),
+    ("acdt", Token { token: TokenType::Zone, value: 37800, zeros: 0 }),
+    ("aest", Token { token: TokenType::Zone, value: 36000, zeros: 0 }),
+    ("aedt", Token { token: TokenType::Zone, value: 39600, zeros: 0 }),
+    ("nzst", Token { token: TokenType::Zone, value: 43200, zeros: 0 }),
+    ("nzdt", Token { token: TokenType::Zone, value: 46800, zeros: 0 }),
+    ("hst", Token { token: TokenType::Zone, value: -36000, zeros: 0 }),
+    ("akst", Token { token: TokenType::Zone, value: -32400, zeros: 0 }),
+    ("akdt", Token { token: TokenType::Zone, value: -28800, zeros: 0 }),
+    ("pst", Token { token: TokenType::Zone, value: -28800, zeros: 0 }),
+    ("pdt", Token { token: TokenType::Zone, value: -25200, zeros: 0 }),
+    ("mst", Token { token: TokenType::Zone, value: -25200, zeros: 0 }),
+    ("mdt", Token { token: TokenType::Zone, value: -21600, zeros: 0 }),
+    ("cst", Token { token: TokenType::Zone, value: -21600, zeros: 0 }),
+    ("cdt", Token { token: TokenType::Zone, value: -18000, zeros: 0 }),
+    ("est", Token { token: TokenType::Zone, value: -18000, zeros: 0 }),
+    ("edt", Token { token: TokenType::Zone, value: -14400, zeros: 0 }),
+    ("ast", Token { token: TokenType::Zone, value: -14400, zeros: 0 }),
+    ("adt", Token { token: TokenType::Zone, value: -10800, zeros: 0 }),
+    ("nst", Token { token: TokenType::Zone, value: -12600, zeros: 0 }),
+    ("ndt", Token { token: TokenType::Zone, value: -9000, zeros: 0 }),
 ];
 
+/// Token along with its position and original text in source string
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpannedToken {
+    /// Byte range in source string
+    pub span: Range<usize>,
+    /// Type of the token, or none for words that were not recognized
+    pub token: Option<TokenType>,
+    /// Value of the token, the whole part of a decimal number,
+    /// or zero for a named time zone
+    pub value: i64,
+    /// Value of a decimal number, e.g. 2.5 for "2.5"
+    pub decimal: Option<f64>,
+    /// Identifier of a named time zone, e.g. "Europe/London"
+    pub zone: Option<String>,
+    pub text: String,
+}
+
+impl SpannedToken {
+    fn new(start: usize, text: &str, token: Option<&Token>) -> Self {
+        let decimal = token.filter(|v| v.token.eq(&TokenType::Decimal));
+        let zone = token.filter(|v| v.token.eq(&TokenType::ZoneName));
+
+        Self {
+            span: start..start + text.len(),
+            token: token.map(|v| v.token.to_owned()),
+            value: match (decimal, zone) {
+                (Some(v), _) => v.value / DECIMAL_SCALE,
+                (_, Some(_)) => 0,
+                _ => token.map(|v| v.value).unwrap_or(0),
+            },
+            decimal: decimal.map(|v| v.value as f64 / DECIMAL_SCALE as f64),
+            zone: zone.and_then(|v| v.zone_name()).map(|v| v.to_string()),
+            text: text.to_string(),
+        }
+    }
+}
+
 struct ParsedNumberValue {
     is_timestamp: bool,
     prefix: String,
@@ -205,35 +345,56 @@ impl ParsedNumberValue {
             Err(_) => None,
         }
     }
+
+    fn decimal_value(&self) -> Option<i64> {
+        let (whole, fraction) = self.number.split_once(".")?;
+        let fraction = format!("{:0<9}", &fraction[..fraction.len().min(9)]);
+
+        whole
+            .parse::<i64>()
+            .ok()?
+            .checked_mul(DECIMAL_SCALE)?
+            .checked_add(fraction.parse::<i64>().ok()?)
+    }
 }
 
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub enum TokenType {
+    Decimal,
+    Holiday,
     Integer,
     LongUnit,
     Meridiem,
     Month,
     Nth,
+    Offset,
     ShortUnit,
     Timestamp,
     Unit,
     Weekday,
     Year,
+    Zone,
+    ZoneName,
 }
 
 impl TokenType {
     fn as_name(&self) -> &'static str {
         match self {
+            TokenType::Decimal => "decimal",
+            TokenType::Holiday => "holiday",
             TokenType::Integer => "int",
             TokenType::LongUnit => "long_unit",
             TokenType::Meridiem => "meridiem",
             TokenType::Month => "month",
             TokenType::ShortUnit => "short_unit",
             TokenType::Nth => "nth",
+            TokenType::Offset => "offset",
             TokenType::Timestamp => "timestamp",
             TokenType::Unit => "unit",
             TokenType::Weekday => "wday",
             TokenType::Year => "year",
+            TokenType::Zone => "zone",
+            TokenType::ZoneName => "zone",
         }
     }
 
@@ -262,6 +423,25 @@ impl Token {
         Self { token: TokenType::Integer, value: value, zeros: zeros }
     }
 
+    /// Create zone token from IANA time zone identifier, e.g. "Europe/London"
+    #[cfg(feature = "chrono-tz")]
+    pub fn from_zone_name(name: &str) -> Option<Self> {
+        let index = chrono_tz::TZ_VARIANTS
+            .iter()
+            .position(|v| v.name().eq_ignore_ascii_case(name))?;
+        Some(Self::new(TokenType::ZoneName, index as i64))
+    }
+
+    /// IANA time zone identifier of a named zone token, e.g. "Europe/London"
+    pub(crate) fn zone_name(&self) -> Option<&'static str> {
+        #[cfg(feature = "chrono-tz")]
+        if self.token.eq(&TokenType::ZoneName) {
+            return chrono_tz::TZ_VARIANTS.get(self.value as usize).map(|v| v.name());
+        }
+
+        None
+    }
+
     /// Create token from global identifier
     pub fn from_gid(gid: u32) -> Option<Self> {
         let gid = gid as i64;
@@ -278,11 +458,11 @@ impl Token {
             return Some(Self::new(TokenType::Unit, gid - 300));
         }
 
-        if gid.ge(&401) && gid.le(&407) && !gid.eq(&402) {
+        if gid.ge(&401) && gid.le(&408) && !gid.eq(&402) {
             return Some(Self::new(TokenType::ShortUnit, gid - 400));
         }
 
-        if gid.ge(&501) && gid.le(&507) {
+        if gid.ge(&501) && gid.le(&510) {
             return Some(Self::new(TokenType::LongUnit, gid - 500));
         }
 
@@ -300,11 +480,171 @@ pub enum WeekStartDay {
     Sunday,
 }
 
+/// Weekdays that make up the weekend, Saturday and Sunday by default
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WeekendDays {
+    days: [bool; 7],
+}
+
+impl WeekendDays {
+    pub fn new(weekdays: &[Weekday]) -> Self {
+        let mut days = [false; 7];
+        weekdays.iter().for_each(|v| days[v.num_days_from_monday() as usize] = true);
+        Self { days: days }
+    }
+
+    /// Whether weekday, from 1 for Monday to 7 for Sunday, is a weekend day
+    pub(crate) fn contains(&self, weekday: i64) -> bool {
+        self.days[(weekday - 1).rem_euclid(7) as usize]
+    }
+
+    /// Whether weekend days follow one another, wrapping from Sunday to Monday
+    pub fn is_contiguous(&self) -> bool {
+        (1..=7).filter(|v| self.contains(*v) && !self.contains(v - 1)).count().le(&1)
+    }
+
+    /// First weekday of the weekend and the number of consecutive weekend
+    /// days from it, e.g. (6, 2) for Saturday and Sunday, or None when the
+    /// weekend days do not follow one another
+    pub(crate) fn span(&self) -> Option<(i64, i64)> {
+        if !self.is_contiguous() {
+            return None;
+        }
+
+        let start_day = (1..=7).find(|v| self.contains(*v) && !self.contains(v - 1))?;
+        let length = (0..7).take_while(|v| self.contains(start_day + v)).count();
+        Some((start_day, length as i64))
+    }
+}
+
+impl Default for WeekendDays {
+    fn default() -> Self {
+        Self::new(&[Weekday::Sat, Weekday::Sun])
+    }
+}
+
+/// Order of day, month and year in numeric dates, e.g. "04/01/2023"
+#[derive(Clone, Copy, Default, Eq, PartialEq)]
+pub enum DateOrder {
+    /// Use the only valid reading when one of the values is greater
+    /// than 12, otherwise day first with dots and dashes, and month
+    /// first with slashes
+    #[default]
+    Auto,
+    /// Day, month and year
+    Dmy,
+    /// Month, day and year
+    Mdy,
+    /// Year, month and day, reading month before day when the year is last
+    Ymd,
+}
+
+impl FromStr for DateOrder {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "dmy" => Ok(Self::Dmy),
+            "mdy" => Ok(Self::Mdy),
+            "ymd" => Ok(Self::Ymd),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How to resolve the century of two-digit years, e.g. "01/04/23"
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum TwoDigitYear {
+    /// Years below the pivot are in the 2000s and the rest in the 1900s,
+    /// e.g. pivot 50 reads years as 1950–2049
+    Pivot(u8),
+    /// Year closest to the current time
+    Closest,
+}
+
+impl Default for TwoDigitYear {
+    fn default() -> Self {
+        Self::Pivot(50)
+    }
+}
+
+/// Named period of a day, e.g. "morning" or "EOD", that resolves
+/// into a configurable time of day
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DayPeriod {
+    /// "morning", 09:00 by default
+    Morning,
+    /// "noon" and "midday", 12:00 by default
+    Noon,
+    /// "afternoon", 15:00 by default
+    Afternoon,
+    /// "evening", 18:00 by default
+    Evening,
+    /// "tonight", 21:00 by default
+    Night,
+    /// "EOD" and "end of business", 17:00 by default
+    EndOfDay,
+}
+
+impl FromStr for DayPeriod {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "morning" => Ok(Self::Morning),
+            "noon" => Ok(Self::Noon),
+            "afternoon" => Ok(Self::Afternoon),
+            "evening" => Ok(Self::Evening),
+            "tonight" => Ok(Self::Night),
+            "eod" => Ok(Self::EndOfDay),
+            _ => Err(()),
+        }
+    }
+}
+
+impl DayPeriod {
+    /// Hour and minute the period resolves into, when not configured
+    pub(crate) fn default_time(&self) -> (i64, i64) {
+        match self {
+            Self::Morning => (9, 0),
+            Self::Noon => (12, 0),
+            Self::Afternoon => (15, 0),
+            Self::Evening => (18, 0),
+            Self::Night => (21, 0),
+            Self::EndOfDay => (17, 0),
+        }
+    }
+}
+
+/// How to resolve local time that occurs twice in a time zone, e.g.
+/// when clocks are turned back at the end of daylight saving time
+#[derive(Clone, Copy, Default, Eq, PartialEq)]
+pub enum AmbiguousTime {
+    #[default]
+    Earliest,
+    Latest,
+    Reject,
+}
+
+/// How to resolve local time that does not exist in a time zone, e.g.
+/// when clocks are turned forward at the start of daylight saving time
+#[derive(Clone, Copy, Default, Eq, PartialEq)]
+pub enum SkippedTime {
+    #[default]
+    Forward,
+    Backward,
+    Reject,
+}
+
 #[derive(Eq, PartialEq)]
 pub enum UnitGroup {
     Long,
     Short,
     Default,
+    /// ISO 8601 duration, e.g. "PT1H30M", which uses fixed designators
+    /// rather than unit names
+    Iso,
 }
 
 impl UnitGroup {
@@ -312,19 +652,79 @@ impl UnitGroup {
         match value {
             "long" => Self::Long,
             "short" => Self::Short,
+            "iso" => Self::Iso,
             _ => Self::Default,
         }
     }
 }
 
+/// How the lowest unit shown is rounded when limiting the number of
+/// units in a duration string, e.g. "1w 1d" for "1w 1d 23hrs" when rounding down
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Rounding {
+    /// Round down, leaving out anything below the lowest unit shown
+    #[default]
+    Floor,
+    /// Round to the nearest, with halfway amounts rounded up
+    HalfUp,
+    /// Round up, counting anything below the lowest unit shown as one more
+    Ceil,
+}
+
+impl FromStr for Rounding {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "floor" => Ok(Self::Floor),
+            "half_up" => Ok(Self::HalfUp),
+            "ceil" => Ok(Self::Ceil),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How negative durations are shown, e.g. "-1hr" or "1hr ago"
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SignStyle {
+    /// Leading minus, e.g. "-1hr"
+    #[default]
+    Minus,
+    /// Words for the past and the future, e.g. "1hr ago" or "in 1hr"
+    Relative,
+    /// Prefix and suffix from unit names, e.g. "(1hr)"
+    Custom,
+}
+
+impl FromStr for SignStyle {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "minus" => Ok(Self::Minus),
+            "relative" => Ok(Self::Relative),
+            "custom" => Ok(Self::Custom),
+            _ => Err(()),
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct UnitNames {
+    ago: String,
     day: String,
     days: String,
     hour: String,
     hours: String,
+    future: String,
+    microsecond: String,
+    microseconds: String,
+    millisecond: String,
+    milliseconds: String,
     minute: String,
     minutes: String,
+    negative_prefix: String,
+    negative_suffix: String,
     second: String,
     seconds: String,
     week: String,
@@ -333,7 +733,15 @@ pub struct UnitNames {
 }
 
 impl UnitNames {
-    pub const UNITS_DEFAULT: [(&'static str, &'static str); 10] = [
+    pub const UNITS_DEFAULT: [(&'static str, &'static str); 18] = [
+        (crate::pattern::UNIT_AGO, "ago"),
+        (crate::pattern::UNIT_IN, "in"),
+        (crate::pattern::UNIT_NEGATIVE_PREFIX, "-"),
+        (crate::pattern::UNIT_NEGATIVE_SUFFIX, ""),
+        (crate::pattern::UNIT_MICROSECOND, "us"),
+        (crate::pattern::UNIT_MICROSECONDS, "us"),
+        (crate::pattern::UNIT_MILLISECOND, "ms"),
+        (crate::pattern::UNIT_MILLISECONDS, "ms"),
         (crate::pattern::UNIT_SECOND, "sec"),
         (crate::pattern::UNIT_SECONDS, "sec"),
         (crate::pattern::UNIT_MINUTE, "min"),
@@ -346,7 +754,15 @@ impl UnitNames {
         (crate::pattern::UNIT_WEEKS, "w"),
     ];
 
-    pub const UNITS_LONG: [(&'static str, &'static str); 10] = [
+    pub const UNITS_LONG: [(&'static str, &'static str); 18] = [
+        (crate::pattern::UNIT_AGO, "ago"),
+        (crate::pattern::UNIT_IN, "in"),
+        (crate::pattern::UNIT_NEGATIVE_PREFIX, "-"),
+        (crate::pattern::UNIT_NEGATIVE_SUFFIX, ""),
+        (crate::pattern::UNIT_MICROSECOND, "microsecond"),
+        (crate::pattern::UNIT_MICROSECONDS, "microseconds"),
+        (crate::pattern::UNIT_MILLISECOND, "millisecond"),
+        (crate::pattern::UNIT_MILLISECONDS, "milliseconds"),
         (crate::pattern::UNIT_SECOND, "second"),
         (crate::pattern::UNIT_SECONDS, "seconds"),
         (crate::pattern::UNIT_MINUTE, "minute"),
@@ -359,7 +775,15 @@ impl UnitNames {
         (crate::pattern::UNIT_WEEKS, "weeks"),
     ];
 
-    pub const UNITS_SHORT: [(&'static str, &'static str); 10] = [
+    pub const UNITS_SHORT: [(&'static str, &'static str); 18] = [
+        (crate::pattern::UNIT_AGO, "ago"),
+        (crate::pattern::UNIT_IN, "in"),
+        (crate::pattern::UNIT_NEGATIVE_PREFIX, "-"),
+        (crate::pattern::UNIT_NEGATIVE_SUFFIX, ""),
+        (crate::pattern::UNIT_MICROSECOND, "us"),
+        (crate::pattern::UNIT_MICROSECONDS, "us"),
+        (crate::pattern::UNIT_MILLISECOND, "ms"),
+        (crate::pattern::UNIT_MILLISECONDS, "ms"),
         (crate::pattern::UNIT_SECOND, "s"),
         (crate::pattern::UNIT_SECONDS, "s"),
         (crate::pattern::UNIT_MINUTE, "min"),
@@ -377,6 +801,7 @@ impl UnitNames {
             UnitGroup::Long => Self::UNITS_LONG,
             UnitGroup::Short => Self::UNITS_SHORT,
             UnitGroup::Default => Self::UNITS_DEFAULT,
+            UnitGroup::Iso => return HashMap::new(),
         };
         mapping.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
     }
@@ -388,12 +813,20 @@ impl UnitNames {
         };
 
         Self {
+            ago: names.get("ago").unwrap_or(&String::new()).to_owned(),
             day: names.get("day").unwrap_or(&String::new()).to_owned(),
             days: names.get("days").unwrap_or(&String::new()).to_owned(),
             hour: names.get("hour").unwrap_or(&String::new()).to_owned(),
             hours: names.get("hours").unwrap_or(&String::new()).to_owned(),
+            future: names.get("in").unwrap_or(&String::new()).to_owned(),
+            microsecond: names.get("microsecond").unwrap_or(&String::new()).to_owned(),
+            microseconds: names.get("microseconds").unwrap_or(&String::new()).to_owned(),
+            millisecond: names.get("millisecond").unwrap_or(&String::new()).to_owned(),
+            milliseconds: names.get("milliseconds").unwrap_or(&String::new()).to_owned(),
             minute: names.get("minute").unwrap_or(&String::new()).to_owned(),
             minutes: names.get("minutes").unwrap_or(&String::new()).to_owned(),
+            negative_prefix: names.get("negative_prefix").unwrap_or(&String::new()).to_owned(),
+            negative_suffix: names.get("negative_suffix").unwrap_or(&String::new()).to_owned(),
             second: names.get("second").unwrap_or(&String::new()).to_owned(),
             seconds: names.get("seconds").unwrap_or(&String::new()).to_owned(),
             week: names.get("week").unwrap_or(&String::new()).to_owned(),
@@ -408,6 +841,14 @@ impl UnitNames {
 
     pub(crate) fn add_names(&mut self, custom: HashMap<String, String>) {
         custom.iter().for_each(|(name, value)| match name.as_str() {
+            crate::pattern::UNIT_AGO => self.ago = value.to_owned(),
+            crate::pattern::UNIT_IN => self.future = value.to_owned(),
+            crate::pattern::UNIT_NEGATIVE_PREFIX => self.negative_prefix = value.to_owned(),
+            crate::pattern::UNIT_NEGATIVE_SUFFIX => self.negative_suffix = value.to_owned(),
+            crate::pattern::UNIT_MICROSECOND => self.microsecond = value.to_owned(),
+            crate::pattern::UNIT_MICROSECONDS => self.microseconds = value.to_owned(),
+            crate::pattern::UNIT_MILLISECOND => self.millisecond = value.to_owned(),
+            crate::pattern::UNIT_MILLISECONDS => self.milliseconds = value.to_owned(),
             crate::pattern::UNIT_SECOND => self.seconds = value.to_owned(),
             crate::pattern::UNIT_MINUTE => self.minute = value.to_owned(),
             crate::pattern::UNIT_MINUTES => self.minutes = value.to_owned(),
@@ -423,6 +864,19 @@ impl UnitNames {
         self.separator = if self.day.len() > 1 { " " } else { "" }.to_owned();
     }
 
+    /// Show the sign of a duration, given without one, in given style
+    pub(crate) fn format_sign(&self, duration: String, negative: bool, style: SignStyle) -> String {
+        let result = match (style, negative) {
+            (SignStyle::Minus, true) => format!("-{}", duration),
+            (SignStyle::Relative, true) => format!("{} {}", duration, self.ago),
+            (SignStyle::Relative, false) => format!("{} {}", self.future, duration),
+            (SignStyle::Custom, true) => format!("{}{}{}", self.negative_prefix, duration, self.negative_suffix),
+            _ => duration,
+        };
+
+        result.trim().to_string()
+    }
+
     pub(crate) fn format_days(&self, amount: i32) -> String {
         let unit = if amount.eq(&1) { &self.day } else { &self.days };
         format!(" {}{}{}", amount, self.separator, unit)
@@ -438,8 +892,26 @@ impl UnitNames {
         format!(" {}{}{}", amount, self.separator, unit)
     }
 
-    pub(crate) fn format_seconds(&self, amount: i32) -> String {
-        let unit = if amount.eq(&1) { &self.second } else { &self.seconds };
+    pub(crate) fn format_microseconds(&self, amount: f64) -> String {
+        let unit = if amount.eq(&1.0) {
+            &self.microsecond
+        } else {
+            &self.microseconds
+        };
+        format!(" {}{}{}", amount, self.separator, unit)
+    }
+
+    pub(crate) fn format_milliseconds(&self, amount: f64) -> String {
+        let unit = if amount.eq(&1.0) {
+            &self.millisecond
+        } else {
+            &self.milliseconds
+        };
+        format!(" {}{}{}", amount, self.separator, unit)
+    }
+
+    pub(crate) fn format_seconds(&self, amount: f64) -> String {
+        let unit = if amount.eq(&1.0) { &self.second } else { &self.seconds };
         format!(" {}{}{}", amount, self.separator, unit)
     }
 
@@ -449,6 +921,146 @@ impl UnitNames {
     }
 }
 
+#[derive(Default)]
+pub struct PhraseNames {
+    a_minute: String,
+    at: String,
+    just_now: String,
+    last: String,
+    last_week: String,
+    month: String,
+    months: String,
+    next: String,
+    next_week: String,
+    today: String,
+    tomorrow: String,
+    year: String,
+    years: String,
+    yesterday: String,
+}
+
+impl PhraseNames {
+    pub const PHRASES_DEFAULT: [(&'static str, &'static str); 14] = [
+        (crate::pattern::PHRASE_JUST_NOW, "just now"),
+        (crate::pattern::PHRASE_A_MINUTE, "a minute"),
+        (crate::pattern::PHRASE_YESTERDAY, "yesterday"),
+        (crate::pattern::PHRASE_TOMORROW, "tomorrow"),
+        (crate::pattern::PHRASE_LAST_WEEK, "last week"),
+        (crate::pattern::PHRASE_NEXT_WEEK, "next week"),
+        (crate::pattern::PHRASE_TODAY, "today"),
+        (crate::pattern::PHRASE_LAST, "last"),
+        (crate::pattern::PHRASE_NEXT, "next"),
+        (crate::pattern::PHRASE_AT, "at"),
+        (crate::pattern::PHRASE_MONTH, "month"),
+        (crate::pattern::PHRASE_MONTHS, "months"),
+        (crate::pattern::PHRASE_YEAR, "year"),
+        (crate::pattern::PHRASE_YEARS, "years"),
+    ];
+
+    pub fn get_defaults() -> HashMap<String, String> {
+        Self::PHRASES_DEFAULT
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    pub(crate) fn from_map(names: HashMap<String, String>) -> Self {
+        Self {
+            a_minute: names.get("a_minute").unwrap_or(&String::new()).to_owned(),
+            at: names.get("at").unwrap_or(&String::new()).to_owned(),
+            just_now: names.get("just_now").unwrap_or(&String::new()).to_owned(),
+            last: names.get("last").unwrap_or(&String::new()).to_owned(),
+            last_week: names.get("last_week").unwrap_or(&String::new()).to_owned(),
+            month: names.get("month").unwrap_or(&String::new()).to_owned(),
+            months: names.get("months").unwrap_or(&String::new()).to_owned(),
+            next: names.get("next").unwrap_or(&String::new()).to_owned(),
+            next_week: names.get("next_week").unwrap_or(&String::new()).to_owned(),
+            today: names.get("today").unwrap_or(&String::new()).to_owned(),
+            tomorrow: names.get("tomorrow").unwrap_or(&String::new()).to_owned(),
+            year: names.get("year").unwrap_or(&String::new()).to_owned(),
+            years: names.get("years").unwrap_or(&String::new()).to_owned(),
+            yesterday: names.get("yesterday").unwrap_or(&String::new()).to_owned(),
+        }
+    }
+
+    pub(crate) fn add_names(&mut self, custom: HashMap<String, String>) {
+        custom.iter().for_each(|(name, value)| match name.as_str() {
+            crate::pattern::PHRASE_JUST_NOW => self.just_now = value.to_owned(),
+            crate::pattern::PHRASE_A_MINUTE => self.a_minute = value.to_owned(),
+            crate::pattern::PHRASE_YESTERDAY => self.yesterday = value.to_owned(),
+            crate::pattern::PHRASE_TOMORROW => self.tomorrow = value.to_owned(),
+            crate::pattern::PHRASE_LAST_WEEK => self.last_week = value.to_owned(),
+            crate::pattern::PHRASE_NEXT_WEEK => self.next_week = value.to_owned(),
+            crate::pattern::PHRASE_TODAY => self.today = value.to_owned(),
+            crate::pattern::PHRASE_LAST => self.last = value.to_owned(),
+            crate::pattern::PHRASE_NEXT => self.next = value.to_owned(),
+            crate::pattern::PHRASE_AT => self.at = value.to_owned(),
+            crate::pattern::PHRASE_MONTH => self.month = value.to_owned(),
+            crate::pattern::PHRASE_MONTHS => self.months = value.to_owned(),
+            crate::pattern::PHRASE_YEAR => self.year = value.to_owned(),
+            crate::pattern::PHRASE_YEARS => self.years = value.to_owned(),
+            _ => {}
+        });
+    }
+
+    /// Show time relative to current time, e.g. "yesterday" or "in 3 hours", from
+    /// the difference in seconds and the number of calendar days in between
+    pub(crate) fn format_relative(&self, units: &UnitNames, seconds: i64, days: i64) -> String {
+        let negative = seconds.lt(&0);
+        let seconds = seconds.abs();
+
+        let result = match seconds {
+            v if v.lt(&45) => return self.just_now.to_owned(),
+            v if v.lt(&90) => self.a_minute.to_owned(),
+            v if v.lt(&2700) => units.format_minutes((v as f64 / 60.0).round() as i32),
+            v if v.lt(&79200) || days.eq(&0) => units.format_hours((v as f64 / 3600.0).round() as i32),
+            _ if days.eq(&1) && negative => return self.yesterday.to_owned(),
+            _ if days.eq(&1) => return self.tomorrow.to_owned(),
+            _ if days.lt(&7) => units.format_days(days as i32),
+            _ if days.lt(&14) && negative => return self.last_week.to_owned(),
+            _ if days.lt(&14) => return self.next_week.to_owned(),
+            _ if days.lt(&28) => units.format_weeks((days / 7) as i32),
+            _ => self.format_months(units, days),
+        };
+
+        units.format_sign(result.trim().to_string(), negative, SignStyle::Relative)
+    }
+
+    /// Show days as months, or as years from 12 months onwards, using
+    /// average lengths of a month and a year
+    fn format_months(&self, units: &UnitNames, days: i64) -> String {
+        let months = ((days as f64 / 30.4375).round() as i32).max(1);
+
+        let (amount, one, many) = match months.lt(&12) {
+            true => (months, &self.month, &self.months),
+            false => (((days as f64 / 365.25).round() as i32).max(1), &self.year, &self.years),
+        };
+
+        let unit = if amount.eq(&1) { one } else { many };
+        format!(" {}{}{}", amount, units.separator, unit)
+    }
+
+    /// Show date as a calendar phrase, e.g. "Yesterday at 14:00" or "Last Friday", from
+    /// the number of calendar days and weeks in between, using given date for dates
+    /// that are further away, and leaving the time out when it is empty
+    pub(crate) fn format_calendar(&self, days: i64, weeks: i64, weekday: &str, date: &str, time: &str) -> String {
+        let day = match (days, weeks) {
+            (0, _) => self.today.to_owned(),
+            (-1, _) => self.yesterday.to_owned(),
+            (1, _) => self.tomorrow.to_owned(),
+            (_, 0) => weekday.to_owned(),
+            (_, -1) => format!("{} {}", self.last, weekday),
+            (_, 1) => format!("{} {}", self.next, weekday),
+            _ => return capitalize(date),
+        };
+
+        match time.is_empty() {
+            true => capitalize(day.trim()),
+            false => capitalize(format!("{} {} {}", day, self.at, time).trim()),
+        }
+    }
+}
+
 struct TokenList {
     tokens: HashMap<String, Token>,
 }
@@ -470,13 +1082,67 @@ impl TokenList {
 
         match self.tokens.get(lowercased) {
             Some(v) => Some(v.to_owned()),
-            None => None,
+            None => self.find_number_compound(lowercased),
+        }
+    }
+
+    /// Find number that is spelled out as tens and ones joined
+    /// with a hyphen, e.g. "twenty-one" or "ninety-nine"
+    fn find_number_compound(&self, source: &str) -> Option<Token> {
+        let (tens, ones) = source.split_once("-")?;
+
+        let tens = self.tokens.get(tens).filter(|v| v.token.eq(&TokenType::Integer))?;
+        let ones = self.tokens.get(ones).filter(|v| v.token.eq(&TokenType::Integer))?;
+
+        if tens.value.lt(&20) || tens.value.gt(&90) || tens.value % 10 != 0 {
+            return None;
         }
+
+        if ones.value.lt(&1) || ones.value.gt(&9) {
+            return None;
+        }
+
+        Some(Token::new_integer(tens.value + ones.value, 0))
+    }
+}
+
+/// Name of a month or weekday from custom tokens, or from standard tokens when
+/// there is none, preferring the longest name, e.g. "April" rather than "Apr"
+pub(crate) fn token_name(custom: &HashMap<String, Token>, token: TokenType, value: i64) -> String {
+    let is_match = |v: &Token| v.token.eq(&token) && v.value.eq(&value);
+
+    let custom_name = custom
+        .iter()
+        .filter(|(_, v)| is_match(v))
+        .map(|(k, _)| k.to_owned())
+        .max_by_key(|k| (k.chars().count(), k.to_owned()));
+
+    if let Some(name) = custom_name {
+        return name;
+    }
+
+    STANDARD_TOKENS
+        .iter()
+        .filter(|(_, v)| is_match(v))
+        .map(|(k, _)| capitalize(k))
+        .max_by_key(|k| k.chars().count())
+        .unwrap_or_default()
+}
+
+/// Uppercase the first character of a string, e.g. "yesterday" into "Yesterday"
+fn capitalize(value: &str) -> String {
+    let mut chars = value.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
     }
 }
 
 pub(crate) fn is_time_duration(pattern: &str) -> bool {
-    let without_integers: String = pattern.replace(TokenType::Integer.as_pattern().as_str(), "");
+    let without_integers: String = pattern
+        .replace(TokenType::Integer.as_pattern().as_str(), "")
+        .replace(TokenType::Decimal.as_pattern().as_str(), "");
 
     if without_integers.eq(&pattern) {
         return false;
@@ -491,22 +1157,46 @@ pub(crate) fn is_time_duration(pattern: &str) -> bool {
         return false;
     }
 
-    let without_extra: String = without_units.replace("+", "").replace("-", "").replace(" ", "");
+    let without_extra: String = without_units.replace("+", "").replace("-", "");
+
+    without_extra.split(" ").all(|v| v.is_empty() || DURATION_WORDS.contains(&v))
+}
 
-    without_extra.len().eq(&0)
+/// Turn source string into tokens, along with their byte ranges and the
+/// original text, including words that were not recognized as tokens
+pub fn tokenize(source: &str, custom: HashMap<String, Token>) -> Vec<SpannedToken> {
+    tokenize_source(source, custom).2
 }
 
 /// Turn source string into a pattern, and list of extracted tokens
-pub(crate) fn tokenize(source: &str, custom: HashMap<String, Token>) -> (String, Vec<Token>) {
+pub(crate) fn tokenize_pattern(source: &str, custom: HashMap<String, Token>) -> (String, Vec<Token>) {
+    let (out_pattern, out_values, _) = tokenize_source(source, custom);
+    (out_pattern, out_values)
+}
+
+/// Find byte ranges of words in source string that were not recognized
+/// as tokens, e.g. "ago" in "1 day ago"
+pub(crate) fn find_words(source: &str, custom: HashMap<String, Token>) -> Vec<Range<usize>> {
+    tokenize(source, custom)
+        .into_iter()
+        .filter(|v| v.token.is_none())
+        .map(|v| v.span)
+        .collect()
+}
+
+/// Turn source string into a pattern, list of extracted tokens and
+/// the same tokens with their positions in source string
+fn tokenize_source(source: &str, custom: HashMap<String, Token>) -> (String, Vec<Token>, Vec<SpannedToken>) {
     let mut out_pattern: String = String::new();
     let mut out_values = vec![];
+    let mut out_spans = vec![];
 
     if source.len().lt(&1) {
-        return (out_pattern, out_values);
+        return (out_pattern, out_values, out_spans);
     }
 
     let token_list = TokenList::new(custom);
-    let last_index: usize = source.len() - 1;
+    let last_index: usize = source.char_indices().last().unwrap().0;
     let mut prev_char = String::new();
     let mut part_start = 0;
 
@@ -519,20 +1209,40 @@ pub(crate) fn tokenize(source: &str, custom: HashMap<String, Token>) -> (String,
     for (list_index, (part_index, part_char)) in source_letters.iter().enumerate() {
         let mut part_chars = "";
         let mut part_letter: String = String::new();
+        let mut part_offset: Option<Token> = None;
 
         let curr_char: &str = &part_char;
+        let curr_start = part_start;
         let next_char = source_letters.get(list_index + 1).unwrap_or(&(0, String::new())).1.to_owned();
 
-        if BOUNDARY_CHARS.contains(&curr_char)
+        // Characters that were already consumed as a part of an offset
+        if part_index.lt(&part_start) {
+            prev_char = curr_char.to_owned();
+            continue;
+        }
+
+        let parsed_part = match part_index.eq(&part_start) {
+            true => parse_zone_name(source, *part_index).or_else(|| parse_phrase(&token_list, source, *part_index)),
+            false => None,
+        };
+
+        if let Some((offset_length, offset_token)) =
+            parsed_part.or_else(|| parse_offset(&token_list, source, *part_index))
+        {
+            part_chars = &source[part_start..*part_index];
+            part_offset = Some(offset_token);
+            part_start = part_index + offset_length;
+        } else if (BOUNDARY_CHARS.contains(&curr_char) && !is_word_hyphen(&prev_char, curr_char, &next_char))
             || (CONDITIONAL_CHARS.contains(&curr_char)
                 && is_value_boundary(&prev_char, "-")
-                && is_value_boundary(&next_char, ""))
+                && is_value_boundary(&next_char, "")
+                && !is_decimal_point(&token_list, source, *part_index))
         {
             part_chars = &source[part_start..*part_index];
             part_letter.push_str(&curr_char);
             part_start = part_index + 1;
         } else if part_index.eq(&last_index) {
-            part_chars = &source[part_start..part_index + 1];
+            part_chars = &source[part_start..];
         }
 
         prev_char = curr_char.to_owned();
@@ -541,70 +1251,337 @@ pub(crate) fn tokenize(source: &str, custom: HashMap<String, Token>) -> (String,
             part_letter = String::from(" ");
         }
 
-        if part_chars.eq("") {
-            if out_values.is_empty() || !&part_letter.eq(" ") {
-                out_pattern.push_str(&part_letter);
+        push_part(&token_list, curr_start, part_chars, &part_letter, &mut out_pattern, &mut out_values, &mut out_spans);
+
+        // Offsets and zones are always treated as a separate part of the pattern
+        if let Some(offset_token) = part_offset {
+            if !out_pattern.is_empty() && !out_pattern.ends_with(" ") {
+                out_pattern.push(' ');
             }
 
-            continue;
+            out_pattern.push_str(&offset_token.token.as_pattern());
+            out_pattern.push(' ');
+            out_spans.push(SpannedToken::new(*part_index, &source[*part_index..part_start], Some(&offset_token)));
+            out_values.push(offset_token);
         }
+    }
 
-        if let Some(string_value) = token_list.find_token(&part_chars) {
-            out_values.push(string_value.clone());
-            out_pattern.push_str(&string_value.token.as_pattern());
-            out_pattern.push_str(&part_letter);
-            continue;
+    (out_pattern.trim().to_string(), out_values, out_spans)
+}
+
+/// Turn a single part of source string into tokens, and add them
+/// into the pattern along with the boundary character that followed
+fn push_part(
+    token_list: &TokenList,
+    part_start: usize,
+    part_chars: &str,
+    part_letter: &str,
+    out_pattern: &mut String,
+    out_values: &mut Vec<Token>,
+    out_spans: &mut Vec<SpannedToken>,
+) {
+    if part_chars.eq("") {
+        if out_values.is_empty() || !part_letter.eq(" ") {
+            out_pattern.push_str(part_letter);
         }
 
-        let parsed_number = parse_string_and_number(part_chars);
+        return;
+    }
 
-        // Just a number, or a special prefix
-        if parsed_number.is_only_number() {
-            if let Some(number_token) = create_integer_token(&parsed_number) {
-                out_values.push(number_token.clone());
+    if let Some(string_value) = token_list.find_token(part_chars) {
+        if string_value.token.eq(&TokenType::Weekday) {
+            into_ordinal_second(out_pattern, out_values, out_spans);
+        }
 
-                // When timestamp parsing has failed, keep the prefix
-                if parsed_number.is_timestamp
-                    && number_token.token.ne(&TokenType::Timestamp)
-                    && !parsed_number.prefix.is_empty()
-                {
-                    out_pattern.push_str(&parsed_number.prefix);
-                }
+        if let Some(word) = into_business_days(&string_value, out_pattern, out_spans) {
+            let business_days = Token::new(TokenType::LongUnit, 10);
+
+            out_spans.push(SpannedToken {
+                span: word.span.start..part_start + part_chars.len(),
+                token: Some(TokenType::LongUnit),
+                value: business_days.value,
+                decimal: None,
+                zone: None,
+                text: format!("{} {}", word.text, part_chars),
+            });
+            out_values.push(business_days.clone());
+            out_pattern.push_str(&business_days.token.as_pattern());
+            out_pattern.push_str(part_letter);
+            return;
+        }
+
+        out_spans.push(SpannedToken::new(part_start, part_chars, Some(&string_value)));
+        out_values.push(string_value.clone());
+        out_pattern.push_str(&string_value.token.as_pattern());
+        out_pattern.push_str(part_letter);
+        return;
+    }
 
-                out_pattern.push_str(&number_token.token.as_pattern());
-                out_pattern.push_str(&part_letter);
+    if let Some((prefix, number_token)) = parse_prefix_word(part_chars) {
+        out_spans.push(SpannedToken::new(part_start, part_chars, Some(&number_token)));
+        out_pattern.push_str(prefix);
+        out_pattern.push_str(&number_token.token.as_pattern());
+        out_pattern.push_str(part_letter);
+        out_values.push(number_token);
+        return;
+    }
+
+    if let Some(year_token) = parse_short_year(part_chars) {
+        out_spans.push(SpannedToken::new(part_start, part_chars, Some(&year_token)));
+        out_pattern.push_str(&year_token.token.as_pattern());
+        out_pattern.push_str(part_letter);
+        out_values.push(year_token);
+        return;
+    }
+
+    let parsed_number = parse_string_and_number(part_chars);
+
+    // Just a number, or a special prefix
+    if parsed_number.is_only_number() {
+        let number_token = create_integer_token(&parsed_number);
+        out_spans.push(SpannedToken::new(part_start, part_chars, number_token.as_ref()));
+
+        if let Some(number_token) = number_token {
+            out_values.push(number_token.clone());
+
+            // When timestamp parsing has failed, keep the prefix
+            if parsed_number.is_timestamp
+                && number_token.token.ne(&TokenType::Timestamp)
+                && !parsed_number.prefix.is_empty()
+            {
+                out_pattern.push_str(&parsed_number.prefix);
             }
-            continue;
-        }
 
-        // Unknown string only, include as-is
-        if parsed_number.is_only_string() {
-            out_pattern.push_str(&part_chars);
-            out_pattern.push_str(&part_letter);
-            continue;
+            out_pattern.push_str(&number_token.token.as_pattern());
+            out_pattern.push_str(part_letter);
         }
+        return;
+    }
+
+    // Unknown string only, include in lowercase to match patterns
+    if parsed_number.is_only_string() {
+        out_spans.push(SpannedToken::new(part_start, part_chars, None));
+        out_pattern.push_str(&part_chars.to_lowercase());
+        out_pattern.push_str(part_letter);
+        return;
+    }
 
-        let mut combo_pattern = String::new();
+    let mut combo_pattern = String::new();
+    let number_token = create_integer_token(&parsed_number);
+    out_spans.push(SpannedToken::new(part_start, &parsed_number.number, number_token.as_ref()));
 
-        if let Some(number_token) = create_integer_token(&parsed_number) {
-            out_values.push(number_token.clone());
-            combo_pattern.push_str(&number_token.token.as_pattern());
-        } else {
-            combo_pattern.push_str(&parsed_number.number);
+    if let Some(number_token) = number_token {
+        out_values.push(number_token.clone());
+        combo_pattern.push_str(&number_token.token.as_pattern());
+    } else {
+        combo_pattern.push_str(&parsed_number.number);
+    }
+
+    let string_token = token_list.find_token(&parsed_number.prefix);
+    let string_start = part_start + parsed_number.number.len();
+    out_spans.push(SpannedToken::new(string_start, &parsed_number.prefix, string_token.as_ref()));
+
+    if let Some(string_token) = string_token {
+        // Offset directly after a number, e.g. "12:00Z", is a separate part
+        if string_token.token.eq(&TokenType::Offset) {
+            combo_pattern.push(' ');
         }
 
-        if let Some(string_token) = token_list.find_token(&parsed_number.prefix) {
-            out_values.push(string_token.clone());
-            combo_pattern.push_str(&string_token.token.as_pattern());
-        } else {
-            combo_pattern.push_str(&parsed_number.prefix);
+        out_values.push(string_token.clone());
+        combo_pattern.push_str(&string_token.token.as_pattern());
+    } else {
+        combo_pattern.push_str(&parsed_number.prefix.to_lowercase());
+    }
+
+    out_pattern.push_str(&combo_pattern);
+    out_pattern.push_str(part_letter);
+}
+
+/// Parse a numeric UTC offset, such as "+02:00", "-0700" or "+02", that follows
+/// a time of day, and return the length of it along with the offset token
+fn parse_offset(token_list: &TokenList, source: &str, index: usize) -> Option<(usize, Token)> {
+    let sign: i64 = match source[index..].chars().next() {
+        Some('+') => 1,
+        Some('-') => -1,
+        _ => return None,
+    };
+
+    // Offset is only accepted right after a time of day, e.g. "12:00+02:00" or
+    // "12:00 -0700", so that it can't be confused with numeric unit offsets
+    let before = source[..index].trim_end();
+    let is_spaced = before.len().lt(&index);
+    let last_word = before.rsplit(" ").next().unwrap_or("");
+
+    if !last_word.contains(":") || !last_word.ends_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let after = &source[index + 1..];
+    let value = &after[..after.find(|c: char| c.eq(&' ') || c.eq(&',')).unwrap_or(after.len())];
+
+    if !value.is_ascii() {
+        return None;
+    }
+
+    let (hours, minutes) = match value.len() {
+        2 if !is_spaced => (&value[0..2], "00"),
+        4 => (&value[0..2], &value[2..4]),
+        5 if value[2..3].eq(":") => (&value[0..2], &value[3..5]),
+        _ => return None,
+    };
+
+    if !hours.chars().chain(minutes.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let hours = hours.parse::<i64>().ok()?;
+    let minutes = minutes.parse::<i64>().ok()?;
+
+    if hours.gt(&23) || minutes.gt(&59) {
+        return None;
+    }
+
+    // Number followed by a unit is an amount, e.g. "12:00 +1000 seconds"
+    let next_word = after[value.len()..].trim_start();
+    let next_word = &next_word[..next_word.find(|c: char| c.eq(&' ') || c.eq(&',')).unwrap_or(next_word.len())];
+
+    if token_list.find_token(next_word).is_some_and(|v| v.token.is_unit()) {
+        return None;
+    }
+
+    Some((value.len() + 1, Token::new(TokenType::Offset, sign * (hours * 3600 + minutes * 60))))
+}
+
+/// Parse an IANA time zone identifier, such as "Europe/London", that starts
+/// a word, and return the length of it along with the zone token
+fn parse_zone_name(source: &str, index: usize) -> Option<(usize, Token)> {
+    if !source[..index].is_empty() && !source[..index].ends_with(" ") {
+        return None;
+    }
+
+    let after = &source[index..];
+    let value = &after[..after.find(|c: char| c.eq(&' ') || c.eq(&',')).unwrap_or(after.len())];
+
+    // Only identifiers with a region are accepted, so that zone names
+    // can't be confused with other words, e.g. "Japan" or "Poland"
+    if !value.contains("/") {
+        return None;
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    if let Some(zone_token) = Token::from_zone_name(value) {
+        return Some((value.len(), zone_token));
+    }
+
+    None
+}
+
+/// Parse a token that is made of several words, such as "good friday",
+/// that starts a word, and return the length of it along with the token
+fn parse_phrase(token_list: &TokenList, source: &str, index: usize) -> Option<(usize, Token)> {
+    if !source[..index].is_empty() && !source[..index].ends_with(" ") {
+        return None;
+    }
+
+    let after = &source[index..];
+
+    token_list
+        .tokens
+        .iter()
+        .filter(|(name, _)| name.contains(" "))
+        .filter(|(name, _)| after.get(..name.len()).is_some_and(|v| v.to_lowercase().eq(*name)))
+        .filter(|(name, _)| after[name.len()..].is_empty() || after[name.len()..].starts_with([' ', ',']))
+        .max_by_key(|(name, _)| name.len())
+        .map(|(name, token)| (name.len(), token.to_owned()))
+}
+
+/// Turn "second" before a weekday into an ordinal rather than a unit
+/// of time, e.g. "second Monday" or "second to last Monday"
+fn into_ordinal_second(out_pattern: &mut String, out_values: &mut [Token], out_spans: &mut [SpannedToken]) {
+    let Some(span) = out_spans.iter_mut().rev().find(|v| v.token.is_some()) else {
+        return;
+    };
+
+    if !span.text.eq_ignore_ascii_case("second") {
+        return;
+    }
+
+    let unit_pattern = TokenType::LongUnit.as_pattern();
+
+    for suffix in [" ", " to last "] {
+        let Some(unit_start) = out_pattern
+            .strip_suffix(&format!("{}{}", unit_pattern, suffix))
+            .map(|v| v.len())
+        else {
+            continue;
+        };
+
+        out_pattern.replace_range(unit_start..unit_start + unit_pattern.len(), &TokenType::Nth.as_pattern());
+        if let Some(unit_token) = out_values.last_mut() {
+            *unit_token = Token::new(TokenType::Nth, 2);
         }
 
-        out_pattern.push_str(&combo_pattern);
-        out_pattern.push_str(&part_letter);
+        span.token = Some(TokenType::Nth);
+        span.value = 2;
+        return;
+    }
+}
+
+/// Remove "business" or "working" before days from the pattern, so that
+/// days are read as business days, e.g. "3 business days"
+fn into_business_days(
+    days: &Token,
+    out_pattern: &mut String,
+    out_spans: &mut Vec<SpannedToken>,
+) -> Option<SpannedToken> {
+    if days.token.ne(&TokenType::LongUnit) || days.value.ne(&4) {
+        return None;
+    }
+
+    let word = out_spans.last().filter(|v| v.token.is_none())?;
+
+    if !BUSINESS_DAY_WORDS.iter().any(|v| word.text.eq_ignore_ascii_case(v)) {
+        return None;
+    }
+
+    let word_start = out_pattern.strip_suffix(&format!("{} ", word.text.to_lowercase()))?.len();
+    out_pattern.truncate(word_start);
+    out_spans.pop()
+}
+
+/// Check that character is a hyphen between two letters, e.g. "twenty-one",
+/// in which case it's a part of the word rather than a boundary
+fn is_word_hyphen(prev_char: &String, curr_char: &str, next_char: &String) -> bool {
+    let is_letter = |v: &String| v.chars().next().is_some_and(|c| c.is_alphabetic());
+    curr_char.eq("-") && is_letter(prev_char) && is_letter(next_char)
+}
+
+/// Check that character is a decimal point of a number that is followed
+/// by a unit, e.g. "1.5 hours" or "2.5d", rather than a boundary
+fn is_decimal_point(token_list: &TokenList, source: &str, index: usize) -> bool {
+    if !source[index..].starts_with(".") {
+        return false;
+    }
+
+    // Integer part must start a word, so that e.g. timestamps are left alone
+    let before = source[..index].trim_end_matches(|c: char| c.is_ascii_digit());
+
+    if before.len().eq(&index) || !(before.is_empty() || before.ends_with([' ', '+', '-'])) {
+        return false;
+    }
+
+    let after = &source[index + 1..];
+    let fraction_length = after.find(|c: char| !c.is_ascii_digit()).unwrap_or(after.len());
+
+    if fraction_length.eq(&0) {
+        return false;
     }
 
-    (out_pattern.trim().to_string(), out_values)
+    let after = &after[fraction_length..];
+    let after = after.strip_prefix(" ").unwrap_or(after);
+    let unit = &after[..after.find(|c: char| c.eq(&' ') || c.eq(&',')).unwrap_or(after.len())];
+
+    token_list.find_token(unit).is_some_and(|v| v.token.is_unit())
 }
 
 /// Check that character is a boundary for value
@@ -621,12 +1598,24 @@ fn parse_string_and_number(part_chars: &str) -> ParsedNumberValue {
     let mut curr_number = String::new();
     let mut curr_string = String::new();
 
-    for (_, curr_char) in part_chars.char_indices() {
+    for (curr_index, curr_char) in part_chars.char_indices() {
         if !is_timestamp && curr_string.is_empty() && curr_char.is_digit(10) {
             curr_number.push(curr_char);
             continue;
         }
 
+        // Decimal point, only kept in parts when followed by a unit
+        if !is_timestamp
+            && curr_string.is_empty()
+            && curr_char.eq(&'.')
+            && !curr_number.is_empty()
+            && !curr_number.contains(".")
+            && part_chars[curr_index + 1..].starts_with(|c: char| c.is_ascii_digit())
+        {
+            curr_number.push(curr_char);
+            continue;
+        }
+
         if is_timestamp && curr_number.is_empty() && curr_char.is_digit(10) {
             curr_number.push(curr_char);
             continue;
@@ -643,9 +1632,42 @@ fn parse_string_and_number(part_chars: &str) -> ParsedNumberValue {
     ParsedNumberValue::new(curr_string, curr_number)
 }
 
+/// Parse a number after a prefix word, e.g. "Q1", into the prefix
+/// as written in patterns and a token for the number
+fn parse_prefix_word(part_chars: &str) -> Option<(&'static str, Token)> {
+    let prefix = PREFIX_WORDS.iter().find(|v| {
+        part_chars.len().gt(&v.len()) && part_chars.get(..v.len()).is_some_and(|p| p.eq_ignore_ascii_case(v))
+    })?;
+
+    let number = &part_chars[prefix.len()..];
+
+    if !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let parsed_number = ParsedNumberValue::new(String::new(), number.to_string());
+    Some((prefix, create_integer_token(&parsed_number)?))
+}
+
+/// Parse a two-digit year with a prefix character into a year token,
+/// leaving the century to be resolved during conversion
+fn parse_short_year(part_chars: &str) -> Option<Token> {
+    let digits = PREFIX_CHARS_YEAR.iter().find_map(|v| part_chars.strip_prefix(v))?;
+
+    if !digits.len().eq(&2) || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(Token::new(TokenType::Year, digits.parse::<i64>().ok()?))
+}
+
 /// Parse a numeric string into an integer token, refining token
 /// type based on the size of the integer
 fn create_integer_token(parsed: &ParsedNumberValue) -> Option<Token> {
+    if !parsed.is_timestamp && parsed.number.contains(".") {
+        return parsed.decimal_value().map(|v| Token::new(TokenType::Decimal, v));
+    }
+
     let Some(number_value) = parsed.number_value() else {
         return None;
     };
@@ -667,6 +1689,65 @@ fn create_integer_token(parsed: &ParsedNumberValue) -> Option<Token> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_tokenize_spans() {
+        let spanned = |start: usize, text: &str, token: Option<TokenType>, value: i64| SpannedToken {
+            span: start..start + text.len(),
+            token: token,
+            value: value,
+            decimal: None,
+            zone: None,
+            text: text.to_string(),
+        };
+
+        assert_eq!(
+            tokenize("Mon, 7 Dec 2023 15:00+02:00 foo", HashMap::new()),
+            vec![
+                spanned(0, "Mon", Some(TokenType::Weekday), 1),
+                spanned(5, "7", Some(TokenType::Integer), 7),
+                spanned(7, "Dec", Some(TokenType::Month), 12),
+                spanned(11, "2023", Some(TokenType::Year), 2023),
+                spanned(16, "15", Some(TokenType::Integer), 15),
+                spanned(19, "00", Some(TokenType::Integer), 0),
+                spanned(21, "+02:00", Some(TokenType::Offset), 7200),
+                spanned(28, "foo", None, 0),
+            ]
+        );
+
+        assert_eq!(
+            tokenize("2.5d ago", HashMap::new()),
+            vec![
+                SpannedToken { decimal: Some(2.5), ..spanned(0, "2.5", Some(TokenType::Decimal), 2) },
+                spanned(3, "d", Some(TokenType::ShortUnit), 4),
+                spanned(5, "ago", None, 0),
+            ]
+        );
+
+        assert_eq!(
+            tokenize("+2 päivää", HashMap::new()),
+            vec![
+                spanned(1, "2", Some(TokenType::Integer), 2),
+                spanned(3, "päivää", None, 0)
+            ]
+        );
+
+        assert_eq!(tokenize("", HashMap::new()), vec![]);
+    }
+
+    #[test]
+    fn test_find_words() {
+        let expect: Vec<(&str, Vec<Range<usize>>)> = vec![
+            ("1 day ago", vec![6..9]),
+            ("next foo", vec![0..4, 5..8]),
+            ("+1dd", vec![2..4]),
+            ("7.2.2023 15:00 UTC", vec![]),
+        ];
+
+        for (from_string, expect_value) in expect {
+            assert_eq!(find_words(from_string, HashMap::new()), expect_value);
+        }
+    }
+
     #[test]
     fn test_is_time_duration() {
         let expect: Vec<(&str, bool)> = vec![
@@ -675,6 +1756,9 @@ mod tests {
             ("-[int] [unit]", true),
             ("+[int] [long_unit]", true),
             ("[int] [long_unit] ago", false),
+            ("half [int] [long_unit]", true),
+            ("[int] [long_unit] and [int] half", true),
+            ("[int] [long_unit] or [int] half", false),
             ("next [long_unit]", false),
         ];
 
@@ -800,6 +1884,9 @@ mod tests {
             ("29th", 29),
             ("30th", 30),
             ("31st", 31),
+            ("third", 3),
+            ("fourth", 4),
+            ("fifth", 5),
         ];
 
         for (from_string, expect_value) in expect {
@@ -1159,6 +2246,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_offsets() {
+        let expect: Vec<(&str, &str, i64)> = vec![
+            ("2023-12-07T15:02:01Z", "[year]-[int]-[int]T[int]:[int]:[int] [offset]", 0),
+            ("2023-12-07T15:02:01+02:00", "[year]-[int]-[int]T[int]:[int]:[int] [offset]", 7200),
+            ("2023-12-07T15:02:01+0530", "[year]-[int]-[int]T[int]:[int]:[int] [offset]", 19800),
+            ("2023-12-07T15:02:01-07", "[year]-[int]-[int]T[int]:[int]:[int] [offset]", -25200),
+            ("2023-12-07 15:02 -0700", "[year]-[int]-[int] [int]:[int] [offset]", -25200),
+            ("2023-12-07 15:02 -07:00", "[year]-[int]-[int] [int]:[int] [offset]", -25200),
+            ("15:02:01.100+02:00", "[int]:[int]:[int].[int] [offset]", 7200),
+        ];
+
+        for (from_string, expect_pattern, expect_value) in expect {
+            let (result_pattern, result_tokens) = tokenize_str(from_string);
+            assert_eq!(result_pattern, expect_pattern);
+            assert_eq!(result_tokens.last().unwrap(), &Token::new(TokenType::Offset, expect_value));
+        }
+
+        let expect: Vec<(&str, &str)> = vec![
+            ("2023-12", "[year]-[int]"),
+            ("15:02 -07", "[int]:[int] -[int]"),
+            ("15:02 +10 minutes", "[int]:[int] +[int] [long_unit]"),
+            ("15:02 +1000 seconds", "[int]:[int] +[year] [long_unit]"),
+            ("15:02 -0700 min", "[int]:[int] -[int] [unit]"),
+            ("15:02 +0100 sec, 16:00", "[int]:[int] +[int] [unit] [int]:[int]"),
+            ("15:02+24:00", "[int]:[int]+[int]:[int]"),
+            ("15:02+02:60", "[int]:[int]+[int]:[int]"),
+        ];
+
+        for (from_string, expect_pattern) in expect {
+            assert_eq!(tokenize_str(from_string).0, expect_pattern);
+        }
+    }
+
+    #[test]
+    fn test_zones() {
+        let expect: Vec<(&str, &str, i64)> = vec![
+            ("3pm EST", "[int][meridiem] [zone]", -18000),
+            ("15:00 utc", "[int]:[int] [zone]", 0),
+            ("Mon 09:00 CET", "[wday] [int]:[int] [zone]", 3600),
+            ("2023-12-07 15:02 IST", "[year]-[int]-[int] [int]:[int] [zone]", 19800),
+        ];
+
+        for (from_string, expect_pattern, expect_value) in expect {
+            let (result_pattern, result_tokens) = tokenize_str(from_string);
+            assert_eq!(result_pattern, expect_pattern);
+            assert_eq!(result_tokens.last().unwrap(), &Token::new(TokenType::Zone, expect_value));
+        }
+
+        // Ambiguous abbreviations can be overridden
+        let custom = HashMap::from([(String::from("ist"), Token::new(TokenType::Zone, 3600))]);
+        let (_, result_tokens) = tokenize_pattern("15:00 IST", custom);
+        assert_eq!(result_tokens.last().unwrap(), &Token::new(TokenType::Zone, 3600));
+
+        // Abbreviations that are also common words are not zones
+        assert_eq!(tokenize_str("10am west").0, "[int][meridiem] west");
+        assert_eq!(tokenize_str("wet 10am").0, "wet [int][meridiem]");
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_zone_names() {
+        let london = Token::from_zone_name("Europe/London").unwrap();
+        let port_au_prince = Token::from_zone_name("America/Port-au-Prince").unwrap();
+
+        let expect: Vec<(&str, &str, &Token)> = vec![
+            ("9am Europe/London", "[int][meridiem] [zone]", &london),
+            ("9am europe/london", "[int][meridiem] [zone]", &london),
+            ("Europe/London 9am", "[zone] [int][meridiem]", &london),
+            ("15:00 America/Port-au-Prince", "[int]:[int] [zone]", &port_au_prince),
+        ];
+
+        for (from_string, expect_pattern, expect_token) in expect {
+            let (result_pattern, result_tokens) = tokenize_str(from_string);
+            assert_eq!(result_pattern, expect_pattern);
+            assert!(result_tokens.contains(expect_token));
+        }
+
+        let result_spans = tokenize("9am Europe/London", HashMap::new());
+        let zone_span = result_spans.last().unwrap();
+        assert_eq!(zone_span.token, Some(TokenType::ZoneName));
+        assert_eq!(zone_span.value, 0);
+        assert_eq!(zone_span.zone, Some(String::from("Europe/London")));
+
+        assert_eq!(tokenize_str("9am Europe/Nowhere").0, "[int][meridiem] europe/nowhere");
+        assert_eq!(tokenize_str("04/01/2023").0, "[int]/[int]/[year]");
+    }
+
     #[test]
     fn test_week_numbers() {
         assert_eq!(
@@ -1225,17 +2400,17 @@ mod tests {
         ]);
 
         assert_eq!(
-            tokenize("next Maanantai", custom_tokens.to_owned()),
+            tokenize_pattern("next Maanantai", custom_tokens.to_owned()),
             (String::from("next [wday]"), vec![Token::new(TokenType::Weekday, 1)]),
         );
 
         assert_eq!(
-            tokenize("next Måndag", custom_tokens.to_owned()),
+            tokenize_pattern("next Måndag", custom_tokens.to_owned()),
             (String::from("next [wday]"), vec![Token::new(TokenType::Weekday, 1)]),
         );
 
         assert_eq!(
-            tokenize("heinäkuu 10. 2023", custom_tokens.to_owned()),
+            tokenize_pattern("heinäkuu 10. 2023", custom_tokens.to_owned()),
             (
                 String::from("[month] [nth] [year]"),
                 vec![
@@ -1247,6 +2422,205 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_phrase_tokens() {
+        let custom_tokens = HashMap::from([
+            (String::from("new year"), Token::new(TokenType::Holiday, 0)),
+            (String::from("new year's eve"), Token::new(TokenType::Holiday, 1)),
+            (String::from("christmas"), Token::new(TokenType::Holiday, 2)),
+        ]);
+
+        let expect: Vec<(&str, &str, Vec<Token>)> = vec![
+            ("New Year", "[holiday]", vec![Token::new(TokenType::Holiday, 0)]),
+            ("new year's eve", "[holiday]", vec![Token::new(TokenType::Holiday, 1)]),
+            ("next new year", "next [holiday]", vec![Token::new(TokenType::Holiday, 0)]),
+            (
+                "new year 2025",
+                "[holiday] [year]",
+                vec![Token::new(TokenType::Holiday, 0), Token::new(TokenType::Year, 2025)],
+            ),
+            (
+                "3 days before New Year",
+                "[int] [long_unit] before [holiday]",
+                vec![
+                    Token::new_integer(3, 0),
+                    Token::new(TokenType::LongUnit, 4),
+                    Token::new(TokenType::Holiday, 0),
+                ],
+            ),
+            ("Christmas", "[holiday]", vec![Token::new(TokenType::Holiday, 2)]),
+            ("renew year", "renew [long_unit]", vec![Token::new(TokenType::LongUnit, 7)]),
+        ];
+
+        for (from_string, expect_pattern, expect_tokens) in expect {
+            assert_eq!(
+                tokenize_pattern(from_string, custom_tokens.to_owned()),
+                (String::from(expect_pattern), expect_tokens),
+                "{}",
+                from_string
+            );
+        }
+
+        let spans = tokenize("next New Year's Eve", custom_tokens);
+        assert_eq!(spans.last().unwrap().span, 5..19);
+    }
+
+    #[test]
+    fn test_decimals() {
+        let expect: Vec<(&str, &str, i64)> = vec![
+            ("1.5 hours", "[decimal] [long_unit]", 1_500_000_000),
+            ("2.5d", "[decimal][short_unit]", 2_500_000_000),
+            ("+0.25h", "+[decimal][short_unit]", 250_000_000),
+            ("-10.05 min", "-[decimal] [unit]", 10_050_000_000),
+            ("1.1234567891 seconds", "[decimal] [long_unit]", 1_123_456_789),
+        ];
+
+        for (from_string, expect_pattern, expect_value) in expect {
+            let (result_pattern, result_tokens) = tokenize_str(from_string);
+            assert_eq!(result_pattern, expect_pattern);
+            assert_eq!(result_tokens.first().unwrap(), &Token::new(TokenType::Decimal, expect_value));
+        }
+
+        // Not followed by a unit
+        for (from_string, expect_pattern) in [
+            ("1.5", "[int].[int]"),
+            ("7.2.2023", "[int].[int].[year]"),
+            ("@1705072948.452", "[timestamp].[int]"),
+            ("12:00:00.5 hours", "[int]:[int]:[int].[int] [long_unit]"),
+        ] {
+            assert_eq!(tokenize_str(from_string).0, expect_pattern);
+        }
+    }
+
+    #[test]
+    fn test_short_years() {
+        let expect: Vec<(&str, &str, i64)> = vec![
+            ("'23", "[year]", 23),
+            ("’05", "[year]", 5),
+            ("1 Apr '23", "[int] [month] [year]", 23),
+            ("Apr '99", "[month] [year]", 99),
+        ];
+
+        for (from_string, expect_pattern, expect_value) in expect {
+            let (result_pattern, result_tokens) = tokenize_str(from_string);
+            assert_eq!(result_pattern, expect_pattern);
+            assert_eq!(result_tokens.last().unwrap(), &Token::new(TokenType::Year, expect_value));
+        }
+
+        // Only exactly two digits are accepted
+        for (from_string, expect_pattern) in [("'2023", "'2023"), ("'5", "'5"), ("01/04/23", "[int]/[int]/[int]")] {
+            assert_eq!(tokenize_str(from_string).0, expect_pattern);
+        }
+    }
+
+    #[test]
+    fn test_prefix_words() {
+        let expect: Vec<(&str, &str, Vec<Token>)> = vec![
+            ("Q1", "Q[int]", vec![Token::new_integer(1, 0)]),
+            ("q4 2024", "Q[int] [year]", vec![Token::new_integer(4, 0), Token::new(TokenType::Year, 2024)]),
+            ("2024-Q3", "[year]-Q[int]", vec![Token::new(TokenType::Year, 2024), Token::new_integer(3, 0)]),
+            ("next quarter", "next [long_unit]", vec![Token::new(TokenType::LongUnit, 8)]),
+            ("+2q", "+[int][short_unit]", vec![Token::new_integer(2, 0), Token::new(TokenType::ShortUnit, 8)]),
+            ("FY2025", "FY[year]", vec![Token::new(TokenType::Year, 2025)]),
+            ("fy24", "FY[int]", vec![Token::new_integer(24, 0)]),
+            ("Q3 FY25", "Q[int] FY[int]", vec![Token::new_integer(3, 0), Token::new_integer(25, 0)]),
+        ];
+
+        for (from_string, expect_pattern, expect_tokens) in expect {
+            assert_eq!(tokenize_str(from_string), (expect_pattern.to_string(), expect_tokens));
+        }
+
+        // Prefix word must be followed by digits only
+        for (from_string, expect_pattern) in [("Qa1", "qa1"), ("Q1a", "q1a")] {
+            assert_eq!(tokenize_str(from_string).0, expect_pattern);
+        }
+    }
+
+    #[test]
+    fn test_ordinal_second() {
+        let expect: Vec<(&str, &str, Vec<Token>)> = vec![
+            ("second Monday", "[nth] [wday]", vec![Token::new(TokenType::Nth, 2), Token::new(TokenType::Weekday, 1)]),
+            (
+                "Second to last Fri",
+                "[nth] to last [wday]",
+                vec![Token::new(TokenType::Nth, 2), Token::new(TokenType::Weekday, 5)],
+            ),
+            ("1 second", "[int] [long_unit]", vec![Token::new_integer(1, 0), Token::new(TokenType::LongUnit, 1)]),
+            (
+                "seconds Monday",
+                "[long_unit] [wday]",
+                vec![Token::new(TokenType::LongUnit, 1), Token::new(TokenType::Weekday, 1)],
+            ),
+        ];
+
+        for (from_string, expect_pattern, expect_tokens) in expect {
+            assert_eq!(tokenize_str(from_string), (expect_pattern.to_string(), expect_tokens));
+        }
+
+        let spans = tokenize("second Monday", HashMap::new());
+        assert_eq!(spans[0].token, Some(TokenType::Nth));
+        assert_eq!(spans[0].value, 2);
+    }
+
+    #[test]
+    fn test_business_days() {
+        let expect: Vec<(&str, &str, Vec<Token>)> = vec![
+            (
+                "3 business days",
+                "[int] [long_unit]",
+                vec![Token::new_integer(3, 0), Token::new(TokenType::LongUnit, 10)],
+            ),
+            ("next Working day", "next [long_unit]", vec![Token::new(TokenType::LongUnit, 10)]),
+            ("business", "business", vec![]),
+            ("busy days", "busy [long_unit]", vec![Token::new(TokenType::LongUnit, 4)]),
+        ];
+
+        for (from_string, expect_pattern, expect_tokens) in expect {
+            assert_eq!(tokenize_str(from_string), (expect_pattern.to_string(), expect_tokens));
+        }
+
+        let spans = tokenize("next business days", HashMap::new());
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[1].span, 5..18);
+        assert_eq!(spans[1].value, 10);
+    }
+
+    #[test]
+    fn test_number_words() {
+        let expect: Vec<(&str, &str, i64)> = vec![
+            ("a day", "[int] [long_unit]", 1),
+            ("an hour", "[int] [long_unit]", 1),
+            ("One", "[int]", 1),
+            ("nineteen", "[int]", 19),
+            ("twenty", "[int]", 20),
+            ("twenty-one", "[int]", 21),
+            ("Ninety-Nine", "[int]", 99),
+            ("-two days", "-[int] [long_unit]", 2),
+            ("+forty-five min", "+[int] [unit]", 45),
+        ];
+
+        for (from_string, expect_pattern, expect_value) in expect {
+            let (result_pattern, result_tokens) = tokenize_str(from_string);
+            assert_eq!(result_pattern, expect_pattern);
+            assert_eq!(result_tokens.first().unwrap(), &Token::new(TokenType::Integer, expect_value));
+        }
+
+        // Not a compound number
+        for from_string in ["ten-one", "twenty-ten", "one-twenty", "twenty-zero"] {
+            assert_eq!(tokenize_str(from_string), (from_string.to_string(), vec![]));
+        }
+
+        let custom_tokens = HashMap::from([
+            (String::from("kaksikymmentä"), Token::new(TokenType::Integer, 20)),
+            (String::from("yksi"), Token::new(TokenType::Integer, 1)),
+        ]);
+
+        assert_eq!(
+            tokenize_pattern("kaksikymmentä-yksi", custom_tokens),
+            (String::from("[int]"), vec![Token::new(TokenType::Integer, 21)]),
+        );
+    }
+
     #[test]
     fn test_ignored() {
         let expect: Vec<&str> = vec!["", "d1", "@not-a-number", "some word", "+word"];
@@ -1276,19 +2650,19 @@ mod tests {
         assert!(Token::from_gid(300).is_none());
         assert!(Token::from_gid(304).is_none());
 
-        for value in 401..=407 {
+        for value in 401..=408 {
             if !value.eq(&402) {
                 assert_eq!(Token::from_gid(value).unwrap(), Token::new(TokenType::ShortUnit, value as i64 - 400));
             }
         }
         assert!(Token::from_gid(400).is_none());
-        assert!(Token::from_gid(408).is_none());
+        assert!(Token::from_gid(409).is_none());
 
-        for value in 501..=507 {
+        for value in 501..=510 {
             assert_eq!(Token::from_gid(value).unwrap(), Token::new(TokenType::LongUnit, value as i64 - 500));
         }
         assert!(Token::from_gid(500).is_none());
-        assert!(Token::from_gid(508).is_none());
+        assert!(Token::from_gid(511).is_none());
 
         for value in 601..=602 {
             assert_eq!(Token::from_gid(value).unwrap(), Token::new(TokenType::Meridiem, value as i64 - 600));
@@ -1297,7 +2671,33 @@ mod tests {
         assert!(Token::from_gid(603).is_none());
     }
 
+    #[test]
+    fn test_weekend_days() {
+        let expect: Vec<(Vec<Weekday>, Option<(i64, i64)>)> = vec![
+            (vec![Weekday::Sat, Weekday::Sun], Some((6, 2))),
+            (vec![Weekday::Sun, Weekday::Sat], Some((6, 2))),
+            (vec![Weekday::Fri, Weekday::Sat], Some((5, 2))),
+            (vec![Weekday::Sun, Weekday::Mon], Some((7, 2))),
+            (vec![Weekday::Sun], Some((7, 1))),
+            (vec![Weekday::Fri, Weekday::Sun], None),
+            (vec![Weekday::Mon, Weekday::Wed, Weekday::Thu], None),
+            (vec![], None),
+        ];
+
+        for (weekdays, expect_span) in expect {
+            assert_eq!(WeekendDays::new(&weekdays).span(), expect_span, "{:?}", weekdays);
+        }
+
+        assert!(WeekendDays::new(&[]).is_contiguous());
+        assert!(WeekendDays::new(&[Weekday::Sat, Weekday::Sun, Weekday::Mon]).is_contiguous());
+        assert!(!WeekendDays::new(&[Weekday::Fri, Weekday::Sun]).is_contiguous());
+
+        assert!(WeekendDays::default().contains(6));
+        assert!(WeekendDays::default().contains(7));
+        assert!(!WeekendDays::default().contains(1));
+    }
+
     fn tokenize_str(source: &str) -> (String, Vec<Token>) {
-        tokenize(source, HashMap::new())
+        tokenize_pattern(source, HashMap::new())
     }
 }
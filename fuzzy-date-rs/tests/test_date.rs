@@ -1,5 +1,12 @@
-use fuzzy_date_rs::token::WeekStartDay;
+use chrono::{Month, NaiveDate, Weekday};
 use fuzzy_date_rs::FuzzyDate;
+use fuzzy_date_rs::calendar::HolidayDates;
+use fuzzy_date_rs::error::ParseError;
+use fuzzy_date_rs::holiday::{HolidayRule, HolidayRules};
+use fuzzy_date_rs::pattern::Pattern;
+use fuzzy_date_rs::token::{DateOrder, DayPeriod, TwoDigitYear, WeekStartDay, WeekendDays};
+use fuzzy_date_rs::token::{Token, TokenType};
+use std::collections::HashMap;
 
 #[test]
 fn test_fixed_dates() {
@@ -22,6 +29,7 @@ fn test_fixed_dates() {
         ("07.02.2023", "2023-02-07 00:00:00 +00:00"),
         ("7.2.2023", "2023-02-07 00:00:00 +00:00"),
         ("2/7/2023", "2023-02-07 00:00:00 +00:00"),
+        ("7-2-2023", "2023-02-07 00:00:00 +00:00"),
         ("Dec 7 2023", "2023-12-07 00:00:00 +00:00"),
         ("Dec 7th 2023", "2023-12-07 00:00:00 +00:00"),
         ("Dec. 7th 2023", "2023-12-07 00:00:00 +00:00"),
@@ -57,6 +65,83 @@ fn test_fixed_dates() {
     }
 }
 
+#[test]
+fn test_fixed_date_order() {
+    let expect: Vec<(&str, DateOrder, &str)> = vec![
+        ("04/01/2023", DateOrder::Dmy, "2023-01-04"),
+        ("04.01.2023", DateOrder::Dmy, "2023-01-04"),
+        ("04-01-2023", DateOrder::Dmy, "2023-01-04"),
+        ("04/01/2023", DateOrder::Mdy, "2023-04-01"),
+        ("04.01.2023", DateOrder::Mdy, "2023-04-01"),
+        ("04-01-2023", DateOrder::Mdy, "2023-04-01"),
+        ("04/01/2023", DateOrder::Ymd, "2023-04-01"),
+        ("04.01.2023", DateOrder::Ymd, "2023-04-01"),
+        ("2023-04-01", DateOrder::Dmy, "2023-04-01"),
+        ("2023-04-01", DateOrder::Mdy, "2023-04-01"),
+        // Unambiguous values are read the only valid way
+        ("13/01/2023", DateOrder::Auto, "2023-01-13"),
+        ("01.13.2023", DateOrder::Auto, "2023-01-13"),
+        ("01-13-2023", DateOrder::Auto, "2023-01-13"),
+        // Ambiguous values follow the separator
+        ("04/01/2023", DateOrder::Auto, "2023-04-01"),
+        ("04.01.2023", DateOrder::Auto, "2023-01-04"),
+        ("04-01-2023", DateOrder::Auto, "2023-01-04"),
+    ];
+
+    for (from_string, date_order, expect_date) in expect {
+        let result_time = FuzzyDate::from_rfc3339("2024-01-12T15:22:28+02:00")
+            .set_date_order(date_order)
+            .to_datetime(from_string);
+
+        assert_eq!(result_time.unwrap().date_naive().to_string(), expect_date.to_string());
+    }
+
+    let result_time = FuzzyDate::from_rfc3339("2024-01-12T15:22:28+02:00")
+        .set_date_order(DateOrder::Dmy)
+        .to_datetime("01/13/2023");
+
+    assert_eq!(result_time, Err(ParseError::OutOfRange));
+}
+
+#[test]
+fn test_fixed_two_digit_years() {
+    let expect: Vec<(&str, DateOrder, TwoDigitYear, &str)> = vec![
+        ("01/04/23", DateOrder::Auto, TwoDigitYear::Pivot(50), "2023-01-04"),
+        ("01.04.23", DateOrder::Auto, TwoDigitYear::Pivot(50), "2023-04-01"),
+        ("01-04-23", DateOrder::Auto, TwoDigitYear::Pivot(50), "2023-04-01"),
+        ("23-04-01", DateOrder::Ymd, TwoDigitYear::Pivot(50), "2023-04-01"),
+        ("23/04/01", DateOrder::Ymd, TwoDigitYear::Pivot(50), "2023-04-01"),
+        ("04/01/23", DateOrder::Dmy, TwoDigitYear::Pivot(50), "2023-01-04"),
+        ("1 Apr 23", DateOrder::Auto, TwoDigitYear::Pivot(50), "2023-04-01"),
+        ("1 Apr '23", DateOrder::Auto, TwoDigitYear::Pivot(50), "2023-04-01"),
+        ("Apr 1 23", DateOrder::Auto, TwoDigitYear::Pivot(50), "2023-04-01"),
+        ("Apr 1 ’23", DateOrder::Auto, TwoDigitYear::Pivot(50), "2023-04-01"),
+        ("Apr 1st '23", DateOrder::Auto, TwoDigitYear::Pivot(50), "2023-04-01"),
+        ("April '23", DateOrder::Auto, TwoDigitYear::Pivot(50), "2023-04-01"),
+        ("01-Apr-23", DateOrder::Auto, TwoDigitYear::Pivot(50), "2023-04-01"),
+        ("Apr-01-23", DateOrder::Auto, TwoDigitYear::Pivot(50), "2023-04-01"),
+        // Century depends on the policy
+        ("01.04.49", DateOrder::Auto, TwoDigitYear::Pivot(50), "2049-04-01"),
+        ("01.04.50", DateOrder::Auto, TwoDigitYear::Pivot(50), "1950-04-01"),
+        ("01.04.75", DateOrder::Auto, TwoDigitYear::Pivot(80), "2075-04-01"),
+        ("01.04.70", DateOrder::Auto, TwoDigitYear::Closest, "2070-04-01"),
+        ("01.04.74", DateOrder::Auto, TwoDigitYear::Closest, "1974-04-01"),
+        ("1 Apr '80", DateOrder::Auto, TwoDigitYear::Closest, "1980-04-01"),
+    ];
+
+    for (from_string, date_order, two_digit_year, expect_date) in expect {
+        let result_time = FuzzyDate::from_rfc3339("2024-01-12T15:22:28+02:00")
+            .set_date_order(date_order)
+            .set_two_digit_year(two_digit_year)
+            .to_datetime(from_string);
+
+        assert_eq!(result_time.unwrap().date_naive().to_string(), expect_date.to_string());
+    }
+
+    let result_time = FuzzyDate::from_rfc3339("2024-01-12T15:22:28+02:00").to_datetime("01/04/123");
+    assert_eq!(result_time, Err(ParseError::OutOfRange));
+}
+
 #[test]
 fn test_fixed_day_month() {
     assert_convert_from_mon(vec![
@@ -116,6 +201,130 @@ fn test_fixed_time() {
     }
 }
 
+#[test]
+fn test_fixed_offsets() {
+    assert_convert_from_mon(vec![
+        ("2023-04-01T12:00:00Z", "2024-01-12T15:22:28+02:00", "2023-04-01 12:00:00 +00:00"),
+        ("2023-04-01T12:00:00+02:00", "2024-01-12T15:22:28+00:00", "2023-04-01 12:00:00 +02:00"),
+        ("2023-04-01T12:00:00.250+05:30", "2024-01-12T15:22:28+00:00", "2023-04-01 12:00:00.250 +05:30"),
+        ("2023-04-01T12:00:00-0700", "2024-01-12T15:22:28+00:00", "2023-04-01 12:00:00 -07:00"),
+        ("2023-04-01 12:00 -0700", "2024-01-12T15:22:28+00:00", "2023-04-01 12:00:00 -07:00"),
+        ("2023-04-01 12:00:00 +02:00", "2024-01-12T15:22:28+00:00", "2023-04-01 12:00:00 +02:00"),
+        ("Sat, 01 Apr 2023 12:00:00 +0200", "2024-01-12T15:22:28+00:00", "2023-04-01 12:00:00 +02:00"),
+        // Relative values are resolved within the given offset
+        ("12:00 +02:00", "2024-01-12T23:22:28+00:00", "2024-01-13 12:00:00 +02:00"),
+        ("monday 12:00Z", "2024-01-14T23:22:28-02:00", "2024-01-15 12:00:00 +00:00"),
+        // Number followed by a unit is an amount rather than an offset
+        ("15:02 +0100 seconds", "2024-01-12T15:22:28+02:00", "2024-01-12 15:03:40 +02:00"),
+        ("15:02 +0130 minutes", "2024-01-12T15:22:28+02:00", "2024-01-12 17:12:00 +02:00"),
+        ("15:02 +0100", "2024-01-12T15:22:28+02:00", "2024-01-12 15:02:00 +01:00"),
+    ]);
+
+    assert_convert_failure(vec![
+        "2023-04-01T12:00:00+24:00",              // Offset out of range
+        "2023-04-01T12:00:00+02:60",              // Offset out of range
+        "2023-04-01T12:00:00+02:00 12:00 +03:00", // Multiple offsets
+    ]);
+}
+
+#[test]
+fn test_zone_abbreviations() {
+    assert_convert_from_mon(vec![
+        ("3pm EST", "2024-01-12T15:22:28+02:00", "2024-01-12 22:00:00 +02:00"),
+        ("15:00 UTC", "2024-01-12T15:22:28+02:00", "2024-01-12 17:00:00 +02:00"),
+        ("Mon 09:00 CET", "2024-01-12T15:22:28+02:00", "2024-01-15 10:00:00 +02:00"),
+        ("2024-01-12 23:30 PST", "2024-01-12T15:22:28+02:00", "2024-01-13 09:30:00 +02:00"),
+        // Relative values are resolved within the zone
+        ("tomorrow UTC", "2024-01-12T23:22:28+02:00", "2024-01-13 02:00:00 +02:00"),
+        ("yesterday EST", "2024-01-12T05:22:28+02:00", "2024-01-10 07:00:00 +02:00"),
+    ]);
+
+    assert_convert_failure(vec![
+        "12:00 UTC EST",    // Multiple zones
+        "12:00 +02:00 UTC", // Both offset and zone
+        "10am west",        // Common word, not a zone
+    ]);
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn test_time_zones() {
+    use fuzzy_date_rs::token::{AmbiguousTime, SkippedTime};
+
+    let helsinki = chrono_tz::Europe::Helsinki;
+    let new_york = chrono_tz::America::New_York;
+
+    assert_convert_tz(
+        vec![
+            // Wall clock time is kept when moving over to daylight saving time
+            ("tomorrow", "2024-03-30T12:00:00+02:00", "2024-03-31 00:00:00 +02:00"),
+            ("tomorrow 9am", "2024-03-30T12:00:00+02:00", "2024-03-31 09:00:00 +03:00"),
+            ("+1 day", "2024-03-30T12:00:00+02:00", "2024-03-31 12:00:00 +03:00"),
+            ("next week", "2024-03-30T12:00:00+02:00", "2024-04-01 12:00:00 +03:00"),
+            ("2024-04-01 12:00", "2024-03-30T12:00:00+02:00", "2024-04-01 12:00:00 +03:00"),
+            ("-1 day", "2024-10-28T12:00:00+02:00", "2024-10-27 12:00:00 +02:00"),
+            ("-1 month", "2024-11-15T12:00:00+02:00", "2024-10-15 12:00:00 +03:00"),
+            // Exact durations are kept as they are
+            ("+24 hours", "2024-03-30T12:00:00+02:00", "2024-03-31 13:00:00 +03:00"),
+            ("+1 hour", "2024-10-27T03:30:00+03:00", "2024-10-27 03:30:00 +02:00"),
+            ("+30 minutes", "2024-03-31T02:45:00+02:00", "2024-03-31 04:15:00 +03:00"),
+            // Explicit offset and timestamps are not moved into time zone
+            ("2024-04-01 12:00 +02:00", "2024-03-30T12:00:00+02:00", "2024-04-01 12:00:00 +02:00"),
+            ("@1711893600", "2024-03-30T12:00:00+02:00", "2024-03-31 14:00:00 +00:00"),
+            // Named zones are converted back into the time zone
+            ("9am Europe/London", "2024-07-01T12:00:00+03:00", "2024-07-01 11:00:00 +03:00"),
+            ("tomorrow 9am America/New_York", "2024-03-09T12:00:00+02:00", "2024-03-10 15:00:00 +02:00"),
+            ("+1 day America/New_York", "2024-03-09T19:00:00+02:00", "2024-03-10 18:00:00 +02:00"),
+            ("3pm EST", "2024-07-01T12:00:00+03:00", "2024-07-01 23:00:00 +03:00"),
+        ],
+        helsinki,
+        AmbiguousTime::Earliest,
+        SkippedTime::Forward,
+    );
+
+    assert_convert_tz(
+        vec![
+            ("+1 day", "2024-03-09T12:00:00-05:00", "2024-03-10 12:00:00 -04:00"),
+            ("9am Europe/London", "2024-07-01T12:00:00-04:00", "2024-07-01 04:00:00 -04:00"),
+        ],
+        new_york,
+        AmbiguousTime::Earliest,
+        SkippedTime::Forward,
+    );
+
+    // Ambiguous local times
+    let ambiguous = vec![
+        (AmbiguousTime::Earliest, Some("2024-10-27 03:30:00 +03:00")),
+        (AmbiguousTime::Latest, Some("2024-10-27 03:30:00 +02:00")),
+        (AmbiguousTime::Reject, None),
+    ];
+
+    for (policy, expect_time) in ambiguous {
+        let current_time = chrono::DateTime::parse_from_rfc3339("2024-10-26T12:00:00+03:00").unwrap();
+        let result_time = FuzzyDate::from_time_tz(current_time.with_timezone(&helsinki))
+            .set_ambiguous_time(policy)
+            .to_datetime("tomorrow 3:30");
+
+        assert_eq!(result_time.ok().map(|v| v.to_string()), expect_time.map(|v| v.to_string()));
+    }
+
+    // Skipped local times
+    let skipped = vec![
+        (SkippedTime::Forward, Some("2024-03-31 04:30:00 +03:00")),
+        (SkippedTime::Backward, Some("2024-03-31 02:30:00 +02:00")),
+        (SkippedTime::Reject, None),
+    ];
+
+    for (policy, expect_time) in skipped {
+        let current_time = chrono::DateTime::parse_from_rfc3339("2024-03-30T12:00:00+02:00").unwrap();
+        let result_time = FuzzyDate::from_time_tz(current_time.with_timezone(&helsinki))
+            .set_skipped_time(policy)
+            .to_datetime("tomorrow 3:30");
+
+        assert_eq!(result_time.ok().map(|v| v.to_string()), expect_time.map(|v| v.to_string()));
+    }
+}
+
 #[test]
 fn test_keywords() {
     assert_convert_from_mon(vec![
@@ -126,6 +335,59 @@ fn test_keywords() {
     ]);
 }
 
+#[test]
+fn test_named_times() {
+    assert_convert_from_mon(vec![
+        ("noon", "2024-01-12T15:22:28+02:00", "2024-01-12 12:00:00 +02:00"),
+        ("midday", "2024-01-12T15:22:28+02:00", "2024-01-12 12:00:00 +02:00"),
+        ("morning", "2024-01-12T15:22:28+02:00", "2024-01-12 09:00:00 +02:00"),
+        ("this afternoon", "2024-01-12T15:22:28+02:00", "2024-01-12 15:00:00 +02:00"),
+        ("this evening", "2024-01-12T15:22:28+02:00", "2024-01-12 18:00:00 +02:00"),
+        ("tonight", "2024-01-12T15:22:28+02:00", "2024-01-12 21:00:00 +02:00"),
+        ("eod", "2024-01-12T15:22:28+02:00", "2024-01-12 17:00:00 +02:00"),
+        ("EOD", "2024-01-12T15:22:28+02:00", "2024-01-12 17:00:00 +02:00"),
+        ("Tonight", "2024-01-12T15:22:28+02:00", "2024-01-12 21:00:00 +02:00"),
+        ("End Of Business", "2024-01-12T15:22:28+02:00", "2024-01-12 17:00:00 +02:00"),
+        ("end of business", "2024-01-12T15:22:28+02:00", "2024-01-12 17:00:00 +02:00"),
+        ("close of business", "2024-01-12T15:22:28+02:00", "2024-01-12 17:00:00 +02:00"),
+        // Combined with days
+        ("tomorrow noon", "2024-01-12T15:22:28+02:00", "2024-01-13 12:00:00 +02:00"),
+        ("noon tomorrow", "2024-01-12T15:22:28+02:00", "2024-01-13 12:00:00 +02:00"),
+        ("yesterday evening", "2024-01-12T15:22:28+02:00", "2024-01-11 18:00:00 +02:00"),
+        ("Friday morning", "2024-01-10T15:22:28+02:00", "2024-01-12 09:00:00 +02:00"),
+        ("morning Friday", "2024-01-10T15:22:28+02:00", "2024-01-12 09:00:00 +02:00"),
+        ("next Monday EOD", "2024-01-10T15:22:28+02:00", "2024-01-15 17:00:00 +02:00"),
+        ("2024-03-01 noon", "2024-01-12T15:22:28+02:00", "2024-03-01 12:00:00 +02:00"),
+        ("Feb 3rd evening", "2024-01-12T15:22:28+02:00", "2024-02-03 18:00:00 +02:00"),
+    ]);
+
+    let expect: Vec<(&str, DayPeriod, u32, u32, &str)> = vec![
+        ("morning", DayPeriod::Morning, 8, 30, "2024-01-12 08:30:00 +02:00"),
+        ("tomorrow EOD", DayPeriod::EndOfDay, 16, 0, "2024-01-13 16:00:00 +02:00"),
+        ("tonight", DayPeriod::Night, 23, 15, "2024-01-12 23:15:00 +02:00"),
+        ("tonight", DayPeriod::Evening, 19, 0, "2024-01-12 21:00:00 +02:00"),
+    ];
+
+    for (from_string, period, hour, minute, expect_time) in expect {
+        let result_time = FuzzyDate::from_rfc3339("2024-01-12T15:22:28+02:00")
+            .set_day_periods(HashMap::from([(period, (hour, minute))]))
+            .to_datetime(from_string);
+
+        assert_eq!(result_time.unwrap().to_string(), expect_time.to_string(), "{}", from_string);
+    }
+
+    let result_time = FuzzyDate::from_rfc3339("2024-01-12T15:22:28+02:00")
+        .set_day_periods(HashMap::from([(DayPeriod::Morning, (25, 0))]))
+        .to_datetime("morning");
+
+    assert_eq!(result_time, Err(ParseError::OutOfRange));
+
+    // Period names are the same words as in source strings
+    assert_eq!("Tonight".parse::<DayPeriod>(), Ok(DayPeriod::Night));
+    assert_eq!("eod".parse::<DayPeriod>(), Ok(DayPeriod::EndOfDay));
+    assert_eq!("night".parse::<DayPeriod>(), Err(()));
+}
+
 #[test]
 fn test_fixed_week_mon() {
     assert_convert_from_mon(vec![
@@ -220,6 +482,47 @@ fn test_wday_ranges() {
     ]);
 }
 
+#[test]
+fn test_nth_wday_ranges() {
+    assert_convert_from_mon(vec![
+        ("2nd Monday of May 2025", "2024-05-12T15:22:28+02:00", "2025-05-12 00:00:00 +02:00"),
+        ("4th fri of Jan 2025", "2024-05-12T15:22:28+02:00", "2025-01-24 00:00:00 +02:00"),
+        ("second Tuesday of March", "2024-05-12T15:22:28+02:00", "2024-03-12 00:00:00 +02:00"),
+        ("5th wed of May", "2024-05-12T15:22:28+02:00", "2024-05-29 00:00:00 +02:00"),
+        ("10th Monday of 2025", "2024-05-12T15:22:28+02:00", "2025-03-10 00:00:00 +02:00"),
+        ("3rd Thursday of next month", "2024-05-12T15:22:28+02:00", "2024-06-20 00:00:00 +02:00"),
+        ("the 3rd Thursday of next month", "2024-05-12T15:22:28+02:00", "2024-06-20 00:00:00 +02:00"),
+        ("the second Tuesday of March", "2024-05-12T15:22:28+02:00", "2024-03-12 00:00:00 +02:00"),
+        ("third fri of this month", "2024-05-12T15:22:28+02:00", "2024-05-17 00:00:00 +02:00"),
+        ("fifth Monday of prev month", "2024-05-12T15:22:28+02:00", "2024-04-29 00:00:00 +02:00"),
+        ("2nd Tuesday of next month", "2024-01-31T15:22:28+02:00", "2024-02-13 00:00:00 +02:00"),
+        ("fourth sat of next year", "2024-05-12T15:22:28+02:00", "2025-01-25 00:00:00 +02:00"),
+        ("1st Wed of Jan 2025 3pm", "2024-05-12T15:22:28+02:00", "2025-01-01 15:00:00 +02:00"),
+        // Counting from the end
+        ("second to last Friday of May", "2024-05-12T15:22:28+02:00", "2024-05-24 00:00:00 +02:00"),
+        ("2nd to last sun of 2024", "2024-05-12T15:22:28+02:00", "2024-12-22 00:00:00 +02:00"),
+        ("3rd to last mon of last month", "2024-05-12T15:22:28+02:00", "2024-04-15 00:00:00 +02:00"),
+        ("the second to last Friday of May", "2024-05-12T15:22:28+02:00", "2024-05-24 00:00:00 +02:00"),
+        // First and last
+        ("first Monday of next month", "2024-05-12T15:22:28+02:00", "2024-06-03 00:00:00 +02:00"),
+        ("last Friday of this month", "2024-05-12T15:22:28+02:00", "2024-05-31 00:00:00 +02:00"),
+        ("first mon of next year", "2024-05-12T15:22:28+02:00", "2025-01-06 00:00:00 +02:00"),
+        ("last sun of last year", "2024-05-12T15:22:28+02:00", "2023-12-31 00:00:00 +02:00"),
+    ]);
+
+    let expect: Vec<(&str, ParseError)> = vec![
+        ("5th Monday of Feb 2024", ParseError::InvalidDate),
+        ("fifth to last Monday of February", ParseError::InvalidDate),
+        ("6th Monday of May", ParseError::InvalidDate),
+        ("2nd Friday of next week", ParseError::UnsupportedUnit { unit: "weeks" }),
+    ];
+
+    for (from_string, expect_error) in expect {
+        let result_time = FuzzyDate::from_rfc3339("2024-05-12T15:22:28+02:00").to_datetime(from_string);
+        assert_eq!(result_time, Err(expect_error), "{}", from_string);
+    }
+}
+
 #[test]
 fn test_year_ranges() {
     assert_convert_from_mon(vec![
@@ -322,6 +625,92 @@ fn test_offset_days() {
     ]);
 }
 
+#[test]
+fn test_offset_number_words() {
+    assert_convert_from_mon(vec![
+        ("a day ago", "2024-01-12T15:22:28+02:00", "2024-01-11 15:22:28 +02:00"),
+        ("an hour ago", "2024-01-12T15:22:28+02:00", "2024-01-12 14:22:28 +02:00"),
+        ("two weeks ago", "2024-01-12T15:22:28+02:00", "2023-12-29 15:22:28 +02:00"),
+        ("Twelve days ago", "2024-01-12T15:22:28+02:00", "2023-12-31 15:22:28 +02:00"),
+        ("twenty minutes", "2024-01-12T15:22:28+02:00", "2024-01-12 15:42:28 +02:00"),
+        ("+twenty-one days", "2024-01-12T15:22:28+02:00", "2024-02-02 15:22:28 +02:00"),
+        ("ninety-nine seconds ago", "2024-01-12T15:22:28+02:00", "2024-01-12 15:20:49 +02:00"),
+        ("past three days", "2024-01-12T15:22:28+02:00", "2024-01-09 15:22:28 +02:00"),
+    ]);
+
+    let custom_tokens = HashMap::from([
+        (String::from("kaksi"), Token::new(TokenType::Integer, 2)),
+        (String::from("päivää"), Token::new(TokenType::LongUnit, 4)),
+    ]);
+
+    let result_time = FuzzyDate::from_rfc3339("2024-01-12T15:22:28+02:00")
+        .set_custom_tokens(custom_tokens)
+        .to_datetime("+kaksi päivää");
+
+    assert_eq!(result_time.unwrap().to_string(), "2024-01-14 15:22:28 +02:00");
+}
+
+#[test]
+fn test_offset_decimals() {
+    assert_convert_from_mon(vec![
+        ("1.5 hours", "2024-01-12T15:22:28+02:00", "2024-01-12 16:52:28 +02:00"),
+        ("2.5d", "2024-01-12T15:22:28+02:00", "2024-01-15 03:22:28 +02:00"),
+        ("+0.25h", "2024-01-12T15:22:28+02:00", "2024-01-12 15:37:28 +02:00"),
+        ("-1.5 minutes", "2024-01-12T15:22:28+02:00", "2024-01-12 15:20:58 +02:00"),
+        ("1.25 seconds", "2024-01-12T15:22:28+02:00", "2024-01-12 15:22:29.250 +02:00"),
+        ("0.001 weeks ago", "2024-01-12T15:22:28+02:00", "2024-01-12 15:12:23.200 +02:00"),
+        ("1.5 days 2 hours", "2024-01-12T15:22:28+02:00", "2024-01-14 05:22:28 +02:00"),
+        ("past 1.5 days", "2024-01-12T15:22:28+02:00", "2024-01-11 03:22:28 +02:00"),
+        ("1.0 months", "2024-01-12T15:22:28+02:00", "2024-02-12 15:22:28 +02:00"),
+        ("half an hour", "2024-01-12T15:22:28+02:00", "2024-01-12 15:52:28 +02:00"),
+        ("half a day ago", "2024-01-12T15:22:28+02:00", "2024-01-12 03:22:28 +02:00"),
+        ("an hour and a half", "2024-01-12T15:22:28+02:00", "2024-01-12 16:52:28 +02:00"),
+        ("two days and a half ago", "2024-01-12T15:22:28+02:00", "2024-01-10 03:22:28 +02:00"),
+        ("2.5 days and a half", "2024-01-12T15:22:28+02:00", "2024-01-15 15:22:28 +02:00"),
+    ]);
+
+    assert_convert_failure(vec![
+        "2 hours and 7 half",   // Only a half
+        "2 hours and 0.5 half", // Only a half
+        "1.5 hours and 1.5",    // Not a unit
+        "half 3 days",          // Only a half of one
+        "half 3 days ago",      // Only a half of one
+    ]);
+}
+
+#[test]
+fn test_offset_future() {
+    assert_convert_from_mon(vec![
+        ("in 3 days", "2024-05-12T15:22:28+02:00", "2024-05-15 15:22:28 +02:00"),
+        ("in 5 min", "2024-05-12T15:22:28+02:00", "2024-05-12 15:27:28 +02:00"),
+        ("in 3d", "2024-05-12T15:22:28+02:00", "2024-05-15 15:22:28 +02:00"),
+        ("in an hour", "2024-05-12T15:22:28+02:00", "2024-05-12 16:22:28 +02:00"),
+        ("in 1.5 hours", "2024-05-12T15:22:28+02:00", "2024-05-12 16:52:28 +02:00"),
+        ("in 2 weeks", "2024-05-12T15:22:28+02:00", "2024-05-26 15:22:28 +02:00"),
+        ("in 1 month", "2024-05-12T15:22:28+02:00", "2024-06-12 15:22:28 +02:00"),
+        ("within 2 hours", "2024-05-12T15:22:28+02:00", "2024-05-12 17:22:28 +02:00"),
+        ("within 30 min", "2024-05-12T15:22:28+02:00", "2024-05-12 15:52:28 +02:00"),
+        ("3 days from now", "2024-05-12T15:22:28+02:00", "2024-05-15 15:22:28 +02:00"),
+        ("2 hrs from now", "2024-05-12T15:22:28+02:00", "2024-05-12 17:22:28 +02:00"),
+        ("3 days hence", "2024-05-12T15:22:28+02:00", "2024-05-15 15:22:28 +02:00"),
+        ("within 2h", "2024-05-12T15:22:28+02:00", "2024-05-12 17:22:28 +02:00"),
+        ("3d from now", "2024-05-12T15:22:28+02:00", "2024-05-15 15:22:28 +02:00"),
+        ("3d hence", "2024-05-12T15:22:28+02:00", "2024-05-15 15:22:28 +02:00"),
+        // Mixed units
+        ("in 1 day 2 hours", "2024-05-12T15:22:28+02:00", "2024-05-13 17:22:28 +02:00"),
+        ("in 1d 2h", "2024-05-12T15:22:28+02:00", "2024-05-13 17:22:28 +02:00"),
+        ("1 day 2 hours from now", "2024-05-12T15:22:28+02:00", "2024-05-13 17:22:28 +02:00"),
+        ("1d 2h from now", "2024-05-12T15:22:28+02:00", "2024-05-13 17:22:28 +02:00"),
+        ("in 2 days 3pm", "2024-05-12T15:22:28+02:00", "2024-05-14 15:00:00 +02:00"),
+    ]);
+
+    assert_convert_failure(vec![
+        "in days",       // Missing amount
+        "in 3 days ago", // Both future and past
+        "3 days from",   // Incomplete
+    ]);
+}
+
 #[test]
 fn test_offset_weekdays() {
     assert_convert_from_mon(vec![
@@ -437,6 +826,278 @@ fn test_offset_years() {
     ]);
 }
 
+#[test]
+fn test_offset_quarters() {
+    assert_convert_from_mon(vec![
+        ("this quarter", "2024-05-12T15:22:28+02:00", "2024-05-12 15:22:28 +02:00"),
+        ("past quarter", "2024-05-12T15:22:28+02:00", "2024-02-12 15:22:28 +02:00"),
+        ("last quarter", "2024-05-12T15:22:28+02:00", "2024-02-12 15:22:28 +02:00"),
+        ("next quarter", "2024-05-12T15:22:28+02:00", "2024-08-12 15:22:28 +02:00"),
+        ("-1q", "2024-05-12T15:22:28+02:00", "2024-02-12 15:22:28 +02:00"),
+        ("+2 quarters", "2024-05-12T15:22:28+02:00", "2024-11-12 15:22:28 +02:00"),
+        ("2 quarters ago", "2024-05-12T15:22:28+02:00", "2023-11-12 15:22:28 +02:00"),
+        ("last 2 quarters", "2024-05-12T15:22:28+02:00", "2023-11-12 15:22:28 +02:00"),
+        // Day is kept within the month
+        ("next quarter", "2024-11-30T15:22:28+02:00", "2025-02-28 15:22:28 +02:00"),
+        // Quarter ranges
+        ("first day of this quarter", "2024-05-12T15:22:28+02:00", "2024-04-01 00:00:00 +02:00"),
+        ("last day of this quarter", "2024-05-12T15:22:28+02:00", "2024-06-30 00:00:00 +02:00"),
+        ("first day of next quarter", "2024-05-12T15:22:28+02:00", "2024-07-01 00:00:00 +02:00"),
+        ("last day of prev quarter", "2024-05-12T15:22:28+02:00", "2024-03-31 00:00:00 +02:00"),
+        ("last day of next quarter", "2024-11-30T15:22:28+02:00", "2025-03-31 00:00:00 +02:00"),
+        // Exact quarters
+        ("Q1", "2024-05-12T15:22:28+02:00", "2024-01-01 00:00:00 +02:00"),
+        ("q3", "2024-05-12T15:22:28+02:00", "2024-07-01 00:00:00 +02:00"),
+        ("Q4 2023", "2024-05-12T15:22:28+02:00", "2023-10-01 00:00:00 +02:00"),
+        ("2023 Q2", "2024-05-12T15:22:28+02:00", "2023-04-01 00:00:00 +02:00"),
+        ("2023-Q2", "2024-05-12T15:22:28+02:00", "2023-04-01 00:00:00 +02:00"),
+        ("Q2 2023 3pm", "2024-05-12T15:22:28+02:00", "2023-04-01 15:00:00 +02:00"),
+    ]);
+
+    assert_convert_failure(vec![
+        "Q0",               // Quarter out of range
+        "Q5 2024",          // Quarter out of range
+        "1.5 quarters",     // Fraction of unit not supported
+        "2015 Q1 2024",     // Multiple years
+        "first of quarter", // Not supported
+    ]);
+}
+
+#[test]
+fn test_offset_weekends() {
+    assert_convert_from_mon(vec![
+        ("this weekend", "2024-05-08T15:22:28+02:00", "2024-05-11 15:22:28 +02:00"),
+        ("this weekend", "2024-05-12T15:22:28+02:00", "2024-05-11 15:22:28 +02:00"),
+        ("last weekend", "2024-05-08T15:22:28+02:00", "2024-05-04 15:22:28 +02:00"),
+        ("prev weekend", "2024-05-11T15:22:28+02:00", "2024-05-04 15:22:28 +02:00"),
+        ("next weekend", "2024-05-08T15:22:28+02:00", "2024-05-18 15:22:28 +02:00"),
+        ("next weekend", "2024-05-12T15:22:28+02:00", "2024-05-18 15:22:28 +02:00"),
+        ("next weekend 10am", "2024-05-08T15:22:28+02:00", "2024-05-18 10:00:00 +02:00"),
+        // Weekend ranges
+        ("first day of this weekend", "2024-05-08T15:22:28+02:00", "2024-05-11 00:00:00 +02:00"),
+        ("last day of this weekend", "2024-05-08T15:22:28+02:00", "2024-05-12 00:00:00 +02:00"),
+        ("last day of next weekend", "2024-05-12T15:22:28+02:00", "2024-05-19 00:00:00 +02:00"),
+        ("first day of last weekend", "2024-05-12T15:22:28+02:00", "2024-05-04 00:00:00 +02:00"),
+    ]);
+
+    assert_convert_failure(vec![
+        "past weekend",               // Exact movement not supported
+        "2 weekends ago",             // Exact movement not supported
+        "first hour of this weekend", // Not supported
+    ]);
+
+    let expect: Vec<(&str, Vec<Weekday>, &str)> = vec![
+        ("this weekend", vec![Weekday::Fri, Weekday::Sat], "2024-05-10 15:22:28 +02:00"),
+        ("next weekend", vec![Weekday::Fri, Weekday::Sat], "2024-05-17 15:22:28 +02:00"),
+        ("last day of this weekend", vec![Weekday::Fri, Weekday::Sat], "2024-05-11 00:00:00 +02:00"),
+        ("this weekend", vec![Weekday::Sun], "2024-05-12 15:22:28 +02:00"),
+        ("last day of this weekend", vec![Weekday::Sun], "2024-05-12 00:00:00 +02:00"),
+    ];
+
+    for (from_string, weekdays, expect_time) in expect {
+        let result_time = FuzzyDate::from_rfc3339("2024-05-08T15:22:28+02:00")
+            .set_weekend_days(WeekendDays::new(&weekdays))
+            .to_datetime(from_string);
+
+        assert_eq!(result_time.unwrap().to_string(), expect_time.to_string(), "{}", from_string);
+    }
+
+    let result_time = FuzzyDate::from_rfc3339("2024-05-08T15:22:28+02:00")
+        .set_weekend_days(WeekendDays::new(&[]))
+        .to_datetime("next weekend");
+
+    assert_eq!(result_time, Err(ParseError::UnsupportedUnit { unit: "weekends" }));
+
+    let result_time = FuzzyDate::from_rfc3339("2024-05-08T15:22:28+02:00")
+        .set_weekend_days(WeekendDays::new(&[Weekday::Fri, Weekday::Sun]))
+        .to_datetime("next weekend");
+
+    assert_eq!(result_time, Err(ParseError::UnsupportedUnit { unit: "weekends" }));
+}
+
+#[test]
+fn test_offset_business_days() {
+    assert_convert_from_mon(vec![
+        ("+3 business days", "2024-05-08T15:22:28+02:00", "2024-05-13 15:22:28 +02:00"),
+        ("+100000 business days", "2024-05-08T15:22:28+02:00", "2407-08-29 15:22:28 +02:00"),
+        ("in 2 working days", "2024-05-10T15:22:28+02:00", "2024-05-14 15:22:28 +02:00"),
+        ("next business day", "2024-05-10T15:22:28+02:00", "2024-05-13 15:22:28 +02:00"),
+        ("next business day", "2024-05-11T15:22:28+02:00", "2024-05-13 15:22:28 +02:00"),
+        ("prev business day", "2024-05-13T15:22:28+02:00", "2024-05-10 15:22:28 +02:00"),
+        ("2 working days ago", "2024-05-13T15:22:28+02:00", "2024-05-09 15:22:28 +02:00"),
+        ("-5 business days", "2024-05-13T15:22:28+02:00", "2024-05-06 15:22:28 +02:00"),
+        ("next business day 9am", "2024-05-10T15:22:28+02:00", "2024-05-13 09:00:00 +02:00"),
+        // Business day ranges
+        ("first business day of this month", "2024-06-12T15:22:28+02:00", "2024-06-03 00:00:00 +02:00"),
+        ("last business day of the month", "2024-06-12T15:22:28+02:00", "2024-06-28 00:00:00 +02:00"),
+        ("last business day of next month", "2024-05-08T15:22:28+02:00", "2024-06-28 00:00:00 +02:00"),
+        ("first business day of next year", "2024-05-08T15:22:28+02:00", "2025-01-01 00:00:00 +02:00"),
+        ("last business day of this quarter", "2024-05-08T15:22:28+02:00", "2024-06-28 00:00:00 +02:00"),
+        ("last day of the month", "2024-06-12T15:22:28+02:00", "2024-06-30 00:00:00 +02:00"),
+    ]);
+
+    assert_convert_failure(vec![
+        "1.5 business days",                 // Fraction of unit not supported
+        "last business day of this weekend", // Not supported
+        "business",                          // Not a unit
+    ]);
+
+    let holidays = HolidayDates::new(vec![
+        NaiveDate::from_ymd_opt(2024, 5, 13).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 5, 31).unwrap(),
+    ]);
+
+    let expect: Vec<(&str, Vec<Weekday>, &str)> = vec![
+        ("+3 business days", vec![Weekday::Sat, Weekday::Sun], "2024-05-14 15:22:28 +02:00"),
+        ("next business day", vec![Weekday::Sat, Weekday::Sun], "2024-05-09 15:22:28 +02:00"),
+        ("3 business days ago", vec![Weekday::Sat, Weekday::Sun], "2024-05-03 15:22:28 +02:00"),
+        ("last business day of this month", vec![Weekday::Sat, Weekday::Sun], "2024-05-30 00:00:00 +02:00"),
+        ("+3 business days", vec![Weekday::Fri, Weekday::Sat], "2024-05-14 15:22:28 +02:00"),
+        ("+2 business days", vec![Weekday::Fri, Weekday::Sat], "2024-05-12 15:22:28 +02:00"),
+        ("last business day of this month", vec![Weekday::Fri, Weekday::Sat], "2024-05-30 00:00:00 +02:00"),
+    ];
+
+    for (from_string, weekdays, expect_time) in expect {
+        let result_time = FuzzyDate::from_rfc3339("2024-05-08T15:22:28+02:00")
+            .set_weekend_days(WeekendDays::new(&weekdays))
+            .set_holiday_calendar(holidays.clone())
+            .to_datetime(from_string);
+
+        assert_eq!(result_time.unwrap().to_string(), expect_time.to_string(), "{}", from_string);
+    }
+
+    let result_time = FuzzyDate::from_rfc3339("2024-05-08T15:22:28+02:00")
+        .set_weekend_days(WeekendDays::new(&[
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ]))
+        .to_datetime("next business day");
+
+    assert_eq!(result_time, Err(ParseError::InvalidDate));
+}
+
+#[test]
+fn test_holidays() {
+    assert_convert_from_mon(vec![
+        ("Christmas", "2024-05-08T15:22:28+02:00", "2024-12-25 00:00:00 +02:00"),
+        ("christmas eve", "2024-05-08T15:22:28+02:00", "2024-12-24 00:00:00 +02:00"),
+        ("New Year's Eve", "2024-05-08T15:22:28+02:00", "2024-12-31 00:00:00 +02:00"),
+        ("Good Friday", "2024-05-08T15:22:28+02:00", "2024-03-29 00:00:00 +02:00"),
+        ("Easter 2025", "2024-05-08T15:22:28+02:00", "2025-04-20 00:00:00 +02:00"),
+        ("this Easter", "2024-05-08T15:22:28+02:00", "2024-03-31 00:00:00 +02:00"),
+        ("next Easter", "2024-05-08T15:22:28+02:00", "2025-04-20 00:00:00 +02:00"),
+        ("next Thanksgiving", "2024-05-08T15:22:28+02:00", "2024-11-28 00:00:00 +02:00"),
+        ("next Thanksgiving", "2024-11-28T15:22:28+02:00", "2025-11-27 00:00:00 +02:00"),
+        ("last Thanksgiving", "2024-05-08T15:22:28+02:00", "2023-11-23 00:00:00 +02:00"),
+        ("prev Memorial Day", "2024-05-28T15:22:28+02:00", "2024-05-27 00:00:00 +02:00"),
+        ("Labor Day 2025", "2024-05-08T15:22:28+02:00", "2025-09-01 00:00:00 +02:00"),
+        ("3 days before New Year", "2024-05-08T15:22:28+02:00", "2023-12-29 00:00:00 +02:00"),
+        ("2 days after Christmas 2023", "2024-05-08T15:22:28+02:00", "2023-12-27 00:00:00 +02:00"),
+        ("1 week before Thanksgiving", "2024-05-08T15:22:28+02:00", "2024-11-21 00:00:00 +02:00"),
+        ("2 hours before Christmas", "2024-05-08T15:22:28+02:00", "2024-12-24 22:00:00 +02:00"),
+        ("Christmas Eve 18:00", "2024-05-08T15:22:28+02:00", "2024-12-24 18:00:00 +02:00"),
+    ]);
+
+    assert_convert_failure(vec![
+        "Boxing Day",                       // Not a US holiday
+        "1.5 days before Christmas",        // Fraction of unit not supported
+        "3 days before New Year 2025 2026", // Only one year allowed
+    ]);
+
+    let expect: Vec<(HolidayRules, &str, &str)> = vec![
+        (HolidayRules::from_locale("gb").unwrap(), "Boxing Day", "2024-12-26 00:00:00 +02:00"),
+        (HolidayRules::from_locale("gb").unwrap(), "Summer Bank Holiday", "2024-08-26 00:00:00 +02:00"),
+        (HolidayRules::from_locale("ca").unwrap(), "Thanksgiving", "2024-10-14 00:00:00 +02:00"),
+        (
+            HolidayRules::new().add_rule("Company Day", HolidayRule::Fixed { month: 9, day: 2 }),
+            "next company day",
+            "2024-09-02 00:00:00 +02:00",
+        ),
+        (
+            HolidayRules::new().add_rule("Midsummer Eve", HolidayRule::Easter { days: 84 }),
+            "Midsummer Eve 2025",
+            "2025-07-13 00:00:00 +02:00",
+        ),
+    ];
+
+    for (rules, from_string, expect_time) in expect {
+        let result_time = FuzzyDate::from_rfc3339("2024-05-08T15:22:28+02:00")
+            .set_holiday_rules(rules)
+            .to_datetime(from_string);
+
+        assert_eq!(result_time.unwrap().to_string(), expect_time.to_string(), "{}", from_string);
+    }
+
+    let result_time = FuzzyDate::from_rfc3339("2024-05-08T15:22:28+02:00")
+        .set_holiday_rules(HolidayRules::new())
+        .to_datetime("Christmas");
+
+    assert_eq!(result_time, Err(ParseError::UnknownToken { span: 0..9 }));
+}
+
+#[test]
+fn test_offset_fiscal_years() {
+    let expect: Vec<(&str, Month, &str)> = vec![
+        ("this fiscal year", Month::July, "2023-07-01 00:00:00 +02:00"),
+        ("last fiscal year", Month::July, "2022-07-01 00:00:00 +02:00"),
+        ("next fiscal year", Month::July, "2024-07-01 00:00:00 +02:00"),
+        ("this fiscal quarter", Month::July, "2024-04-01 00:00:00 +02:00"),
+        ("prev fiscal quarter", Month::July, "2024-01-01 00:00:00 +02:00"),
+        ("next fiscal quarter", Month::July, "2024-07-01 00:00:00 +02:00"),
+        ("this fiscal year 9am", Month::July, "2023-07-01 09:00:00 +02:00"),
+        ("FY2025", Month::July, "2024-07-01 00:00:00 +02:00"),
+        ("fy25", Month::July, "2024-07-01 00:00:00 +02:00"),
+        ("Q1 FY25", Month::July, "2024-07-01 00:00:00 +02:00"),
+        ("Q3 FY2025", Month::July, "2025-01-01 00:00:00 +02:00"),
+        ("first day of FY24", Month::July, "2023-07-01 00:00:00 +02:00"),
+        ("last day of FY2025", Month::July, "2025-06-30 00:00:00 +02:00"),
+        ("first day of this fiscal year", Month::July, "2023-07-01 00:00:00 +02:00"),
+        ("last day of last fiscal year", Month::July, "2023-06-30 00:00:00 +02:00"),
+        ("last day of this fiscal quarter", Month::July, "2024-06-30 00:00:00 +02:00"),
+        ("last day of next fiscal quarter", Month::July, "2024-09-30 00:00:00 +02:00"),
+        ("first day of prev fiscal quarter", Month::July, "2024-01-01 00:00:00 +02:00"),
+        // Fiscal year that matches the calendar year
+        ("this fiscal year", Month::January, "2024-01-01 00:00:00 +02:00"),
+        ("last fiscal quarter", Month::January, "2024-01-01 00:00:00 +02:00"),
+        ("FY2025", Month::January, "2025-01-01 00:00:00 +02:00"),
+        ("Q3 FY2025", Month::January, "2025-07-01 00:00:00 +02:00"),
+        ("last day of FY24", Month::January, "2024-12-31 00:00:00 +02:00"),
+        // Fiscal quarters that cross calendar years
+        ("this fiscal quarter", Month::February, "2024-05-01 00:00:00 +02:00"),
+        ("next fiscal quarter", Month::December, "2024-06-01 00:00:00 +02:00"),
+        ("last fiscal quarter", Month::December, "2023-12-01 00:00:00 +02:00"),
+    ];
+
+    for (from_string, start_month, expect_time) in expect {
+        let result_time = FuzzyDate::from_rfc3339("2024-05-12T15:22:28+02:00")
+            .set_fiscal_year_start(start_month)
+            .to_datetime(from_string);
+
+        assert_eq!(result_time.unwrap().to_string(), expect_time.to_string(), "{}", from_string);
+    }
+
+    let expect: Vec<(&str, ParseError)> = vec![
+        ("Q5 FY2025", ParseError::OutOfRange),
+        ("FY123", ParseError::OutOfRange),
+        ("this fiscal month", ParseError::UnsupportedUnit { unit: "months" }),
+        ("first week of FY2025", ParseError::UnsupportedUnit { unit: "weeks" }),
+        ("2024 FY2025", ParseError::NoMatchingPattern),
+    ];
+
+    for (from_string, expect_error) in expect {
+        let result_time = FuzzyDate::from_rfc3339("2024-05-12T15:22:28+02:00")
+            .set_fiscal_year_start(Month::July)
+            .to_datetime(from_string);
+
+        assert_eq!(result_time, Err(expect_error), "{}", from_string);
+    }
+}
+
 #[test]
 fn test_combinations() {
     assert_convert_from_mon(vec![
@@ -530,6 +1191,9 @@ fn test_combinations_year_rules() {
         "2015 2023-01-01",
         "2015 7.2.2023",
         "2015 2/7/2023",
+        "2015 7-2-2023",
+        "2015 7.2.23",
+        "2015 Apr 1 '23",
         "2015 2023-12-07 15:02",
         "2015 2023-12-07 15:02:01",
         "2015 2023-12-07 15:02:01.000",
@@ -542,6 +1206,228 @@ fn test_combinations_year_rules() {
     ]);
 }
 
+#[test]
+fn test_errors() {
+    let expect: Vec<(&str, ParseError)> = vec![
+        ("", ParseError::NoMatchingPattern),
+        ("foo", ParseError::UnknownToken { span: 0..3 }),
+        ("next Monday bar", ParseError::UnknownToken { span: 12..15 }),
+        ("tuesday 2023-05-01", ParseError::NoMatchingPattern),
+        ("Sun April 1 2023", ParseError::WeekdayMismatch),
+        ("Feb 29th 2023", ParseError::InvalidDate),
+        ("2025-W53", ParseError::InvalidDate),
+        ("1982-04-32", ParseError::OutOfRange),
+        ("23:61:00", ParseError::OutOfRange),
+        ("month 7, 2023", ParseError::UnsupportedUnit { unit: "months" }),
+        ("1.5 months", ParseError::UnsupportedUnit { unit: "months" }),
+        ("+100001 business days", ParseError::OutOfRange),
+        ("-100000000 business days", ParseError::OutOfRange),
+    ];
+
+    for (from_string, expect_error) in expect {
+        let result_time = FuzzyDate::from_rfc3339("2024-01-12T15:22:28+02:00").to_datetime(from_string);
+        assert_eq!(result_time, Err(expect_error));
+    }
+}
+
+#[test]
+fn test_explain() {
+    let result = FuzzyDate::from_rfc3339("2024-01-12T15:22:28+02:00").explain("tomorrow 2pm +1h");
+
+    assert_eq!(result.pattern, "tomorrow [int][meridiem] +[int][short_unit]");
+    assert_eq!(result.date_years, true);
+    assert_eq!(result.reset_time, false);
+    assert_eq!(result.week_start_mon, true);
+    assert_eq!(result.result.unwrap().to_string(), "2024-01-13 15:00:00 +02:00");
+
+    let result_steps = result
+        .steps
+        .iter()
+        .map(|v| (v.pattern.to_owned(), v.matched.as_str(), v.time.to_string(), v.error.is_none()))
+        .collect::<Vec<(Pattern, &str, String, bool)>>();
+
+    assert_eq!(
+        result_steps,
+        vec![
+            (Pattern::Tomorrow, "tomorrow", String::from("2024-01-13 15:22:28 +02:00"), true),
+            (Pattern::TimeMeridiemH, "[int][meridiem]", String::from("2024-01-13 14:00:00 +02:00"), true),
+            (Pattern::PlusUnit, "+[int][short_unit]", String::from("2024-01-13 15:00:00 +02:00"), true),
+        ]
+    );
+
+    let result = FuzzyDate::from_rfc3339("2024-01-12T15:22:28+02:00")
+        .set_first_weekday(WeekStartDay::Sunday)
+        .explain("Sun April 1 2023");
+
+    assert_eq!(result.pattern, "[wday] [month] [int] [year]");
+    assert_eq!(result.date_years, true);
+    assert_eq!(result.reset_time, true);
+    assert_eq!(result.week_start_mon, false);
+    assert_eq!(result.result, Err(ParseError::WeekdayMismatch));
+
+    let result_steps = result
+        .steps
+        .iter()
+        .map(|v| (v.pattern.to_owned(), v.time.to_string(), v.error.to_owned()))
+        .collect::<Vec<(Pattern, String, Option<ParseError>)>>();
+
+    assert_eq!(
+        result_steps,
+        vec![(
+            Pattern::DateWdayMontDayYear,
+            String::from("2024-01-12 15:22:28 +02:00"),
+            Some(ParseError::WeekdayMismatch)
+        )]
+    );
+}
+
+#[test]
+fn test_to_calendar() {
+    let current_time = "2024-01-25T15:22:28+02:00";
+
+    let expect: Vec<(&str, &str)> = vec![
+        ("2024-01-25T09:00:00+02:00", "Today at 9:00"),
+        ("2024-01-24T14:00:00+02:00", "Yesterday at 14:00"),
+        ("2024-01-26T00:00:00+02:00", "Tomorrow"),
+        ("2024-01-25T23:30:00+00:00", "Tomorrow at 1:30"),
+        ("2024-01-22T08:15:00+02:00", "Monday at 8:15"),
+        ("2024-01-19T09:30:00+02:00", "Last Friday at 9:30"),
+        ("2024-01-21T00:00:00+02:00", "Last Sunday"),
+        ("2024-02-02T00:00:00+02:00", "Next Friday"),
+        ("2024-01-10T12:00:00+02:00", "January 10"),
+        ("2024-12-24T00:00:00+02:00", "December 24"),
+        ("2023-04-01T00:00:00+02:00", "April 1, 2023"),
+    ];
+
+    for (target, expect_str) in expect {
+        let target = chrono::DateTime::parse_from_rfc3339(target).unwrap();
+        let result = FuzzyDate::from_rfc3339(current_time).to_calendar(target);
+        assert_eq!(result, expect_str, "{}", target);
+    }
+
+    let expect: Vec<(&str, &str)> = vec![
+        ("2024-01-21T00:00:00+02:00", "Sunday"),
+        ("2024-01-20T00:00:00+02:00", "Last Saturday"),
+        ("2024-01-28T00:00:00+02:00", "Next Sunday"),
+    ];
+
+    for (target, expect_str) in expect {
+        let target = chrono::DateTime::parse_from_rfc3339(target).unwrap();
+        let result = FuzzyDate::from_rfc3339(current_time)
+            .set_first_weekday(WeekStartDay::Sunday)
+            .to_calendar(target);
+        assert_eq!(result, expect_str, "{}", target);
+    }
+
+    let custom_tokens = HashMap::from([
+        ("fre".to_string(), Token::new(TokenType::Weekday, 5)),
+        ("fredag".to_string(), Token::new(TokenType::Weekday, 5)),
+        ("januari".to_string(), Token::new(TokenType::Month, 1)),
+    ]);
+
+    let custom_phrases = HashMap::from([
+        ("last".to_string(), "förra".to_string()),
+        ("at".to_string(), "kl.".to_string()),
+    ]);
+
+    let expect: Vec<(&str, &str)> = vec![
+        ("2024-01-19T09:30:00+02:00", "Förra fredag kl. 9:30"),
+        ("2024-01-10T12:00:00+02:00", "Januari 10"),
+        ("2024-01-22T08:15:00+02:00", "Monday kl. 8:15"),
+    ];
+
+    for (target, expect_str) in expect {
+        let target = chrono::DateTime::parse_from_rfc3339(target).unwrap();
+        let result = FuzzyDate::from_rfc3339(current_time)
+            .set_custom_tokens(custom_tokens.to_owned())
+            .set_custom_phrases(custom_phrases.to_owned())
+            .to_calendar(target);
+        assert_eq!(result, expect_str, "{}", target);
+    }
+}
+
+#[test]
+fn test_to_relative() {
+    let current_time = "2024-01-25T15:22:28+02:00";
+
+    let expect: Vec<(&str, &str)> = vec![
+        ("2024-01-25T15:22:28+02:00", "just now"),
+        ("2024-01-25T15:21:50+02:00", "just now"),
+        ("2024-01-25T15:21:28+02:00", "a minute ago"),
+        ("2024-01-25T15:23:28+02:00", "in a minute"),
+        ("2024-01-25T15:17:28+02:00", "5 minutes ago"),
+        ("2024-01-25T14:40:00+02:00", "42 minutes ago"),
+        ("2024-01-25T14:30:00+02:00", "1 hour ago"),
+        ("2024-01-25T18:00:00+02:00", "in 3 hours"),
+        ("2024-01-25T13:22:28+00:00", "just now"),
+        ("2024-01-25T14:22:28+00:00", "in 1 hour"),
+        ("2024-01-25T00:10:00+02:00", "15 hours ago"),
+        ("2024-01-24T18:00:00+02:00", "21 hours ago"),
+        ("2024-01-24T16:00:00+02:00", "yesterday"),
+        ("2024-01-24T10:00:00+02:00", "yesterday"),
+        ("2024-01-26T23:00:00+02:00", "tomorrow"),
+        ("2024-01-23T16:00:00+02:00", "2 days ago"),
+        ("2024-01-30T10:00:00+02:00", "in 5 days"),
+        ("2024-01-18T10:00:00+02:00", "last week"),
+        ("2024-02-05T10:00:00+02:00", "next week"),
+        ("2024-01-01T10:00:00+02:00", "3 weeks ago"),
+        ("2024-02-20T10:00:00+02:00", "in 3 weeks"),
+        ("2023-12-28T10:00:00+02:00", "1 month ago"),
+        ("2024-03-25T10:00:00+02:00", "in 2 months"),
+        ("2023-03-10T10:00:00+02:00", "11 months ago"),
+        ("2023-02-01T10:00:00+02:00", "1 year ago"),
+        ("2027-01-20T10:00:00+02:00", "in 3 years"),
+    ];
+
+    for (target, expect_str) in expect {
+        let target = chrono::DateTime::parse_from_rfc3339(target).unwrap();
+        let result = FuzzyDate::from_rfc3339(current_time).to_relative(target);
+        assert_eq!(result, expect_str, "{}", target);
+    }
+
+    let custom_units = HashMap::from([
+        ("ago".to_string(), "sitten".to_string()),
+        ("in".to_string(), "".to_string()),
+        ("days".to_string(), "päivää".to_string()),
+        // Phrase keys are not read from unit names
+        ("yesterday".to_string(), "eilen".to_string()),
+    ]);
+
+    let custom_phrases = HashMap::from([
+        ("yesterday".to_string(), "eilen".to_string()),
+        ("months".to_string(), "kuukautta".to_string()),
+    ]);
+
+    let expect: Vec<(&str, &str)> = vec![
+        ("2024-01-24T10:00:00+02:00", "yesterday"),
+        ("2024-01-23T10:00:00+02:00", "2 päivää sitten"),
+        ("2024-01-27T10:00:00+02:00", "2 päivää"),
+    ];
+
+    for (target, expect_str) in expect {
+        let target = chrono::DateTime::parse_from_rfc3339(target).unwrap();
+        let result = FuzzyDate::from_rfc3339(current_time)
+            .set_custom_units(custom_units.to_owned())
+            .to_relative(target);
+        assert_eq!(result, expect_str, "{}", target);
+    }
+
+    let expect: Vec<(&str, &str)> = vec![
+        ("2024-01-24T10:00:00+02:00", "eilen"),
+        ("2024-01-23T10:00:00+02:00", "2 päivää sitten"),
+        ("2023-11-20T10:00:00+02:00", "2 kuukautta sitten"),
+    ];
+
+    for (target, expect_str) in expect {
+        let target = chrono::DateTime::parse_from_rfc3339(target).unwrap();
+        let result = FuzzyDate::from_rfc3339(current_time)
+            .set_custom_units(custom_units.to_owned())
+            .set_custom_phrases(custom_phrases.to_owned())
+            .to_relative(target);
+        assert_eq!(result, expect_str, "{}", target);
+    }
+}
+
 #[test]
 fn test_unsupported() {
     assert_convert_failure(vec![
@@ -574,6 +1460,9 @@ fn test_unsupported() {
         "23:61:00",                  // Invalid time of day
         "tuesday 2023-05-01",        // Invalid use of weekday
         "month 7, 2023",             // Invalid unit for syntax
+        "1.5 months",                // Fraction of unit not supported
+        "half a year",               // Fraction of unit not supported
+        "last 1.5 weeks",            // Fraction of unit not supported
     ])
 }
 
@@ -582,7 +1471,7 @@ fn assert_convert_failure(expect: Vec<&str>) {
 
     for from_string in expect {
         let result_time = FuzzyDate::from_rfc3339(current_time).to_datetime(from_string);
-        assert!(result_time.is_none());
+        assert!(result_time.is_err());
     }
 }
 
@@ -605,3 +1494,21 @@ fn assert_convert_from_sun(expect: Vec<(&str, &str, &str)>) {
         assert_eq!(result_time.unwrap().to_string(), expect_time.to_string());
     }
 }
+
+#[cfg(feature = "chrono-tz")]
+fn assert_convert_tz(
+    expect: Vec<(&str, &str, &str)>,
+    time_zone: chrono_tz::Tz,
+    ambiguous: fuzzy_date_rs::token::AmbiguousTime,
+    skipped: fuzzy_date_rs::token::SkippedTime,
+) {
+    for (from_string, current_time, expect_time) in expect {
+        let current_time = chrono::DateTime::parse_from_rfc3339(current_time).unwrap();
+        let result_time = FuzzyDate::from_time_tz(current_time.with_timezone(&time_zone))
+            .set_ambiguous_time(ambiguous)
+            .set_skipped_time(skipped)
+            .to_datetime(from_string);
+
+        assert_eq!(result_time.unwrap().to_string(), expect_time.to_string());
+    }
+}
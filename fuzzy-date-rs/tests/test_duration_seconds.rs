@@ -1,5 +1,7 @@
-use fuzzy_date_rs::token::UnitGroup;
+use fuzzy_date_rs::error::ParseError;
+use fuzzy_date_rs::token::{Rounding, SignStyle, UnitGroup};
 use fuzzy_date_rs::{FuzzyDuration, FuzzySeconds};
+use std::collections::HashMap;
 
 #[test]
 fn test_to_duration_all() {
@@ -78,6 +80,248 @@ fn test_to_duration_min_max() {
     assert_to_duration("s", "s", vec![(695165.0, "short", "695165s")]);
 }
 
+#[test]
+fn test_to_duration_sub_seconds() {
+    assert_to_duration(
+        "",
+        "",
+        vec![
+            (1.25, "", "1.25sec"),
+            (0.35, "", "0.35sec"),
+            (123.5, "", "2min 3.5sec"),
+            (1.25, "long", "1.25 seconds"),
+            (2.5, "short", "2.5s"),
+            (0.0004, "", ""),
+        ],
+    );
+
+    assert_to_duration("w", "ms", vec![(0.35, "", "350ms"), (123.5, "", "2min 3sec 500ms")]);
+    assert_to_duration("w", "ms", vec![(1.0025, "long", "1 second 2.5 milliseconds")]);
+    assert_to_duration("ms", "ms", vec![(1.25, "", "1250ms")]);
+    assert_to_duration("s", "us", vec![(1.000251, "short", "1s 251us")]);
+    assert_to_duration("ms", "us", vec![(0.001001, "long", "1 millisecond 1 microsecond")]);
+    assert_to_duration("w", "min", vec![(123.5, "", "2min")]);
+
+    // Rounding the lowest unit carries over into higher units
+    assert_to_duration(
+        "w",
+        "s",
+        vec![
+            (59.9996, "", "1min"),
+            (59.9994, "", "59.999sec"),
+            (3599.9999, "", "1hr"),
+            (604799.9999, "long", "1 week"),
+        ],
+    );
+
+    assert_to_duration("w", "ms", vec![(0.9999996, "", "1sec")]);
+
+    // Milliseconds and microseconds are read back only as compact durations
+    for from_seconds in [0.35, 1.25, 123.5] {
+        let into_duration = FuzzyDuration::new().set_min_unit("us").to_duration(from_seconds);
+        let into_seconds = FuzzySeconds::new().set_compact(true).to_seconds(&into_duration);
+        assert_eq!(into_seconds.unwrap(), from_seconds, "{}", into_duration);
+    }
+
+    let into_duration = FuzzyDuration::new().set_sign_style(SignStyle::Relative).to_duration(-3599.9999);
+
+    assert_eq!(into_duration, "1hr ago");
+}
+
+#[test]
+fn test_to_duration_negative() {
+    assert_to_duration(
+        "",
+        "",
+        vec![
+            (-1.0, "", "-1sec"),
+            (-3600.0, "", "-1hr"),
+            (-5400.0, "long", "-1 hour 30 minutes"),
+            (-90062.0, "short", "-1d 1h 1min 2s"),
+            (-0.0004, "", ""),
+        ],
+    );
+
+    let custom_units = HashMap::from([
+        (String::from("negative_prefix"), String::from("(")),
+        (String::from("negative_suffix"), String::from(")")),
+    ]);
+
+    let expect: Vec<(f64, SignStyle, UnitGroup, &str)> = vec![
+        (-3600.0, SignStyle::Relative, UnitGroup::Default, "1hr ago"),
+        (3600.0, SignStyle::Relative, UnitGroup::Default, "in 1hr"),
+        (-5400.0, SignStyle::Relative, UnitGroup::Long, "1 hour 30 minutes ago"),
+        (0.0, SignStyle::Relative, UnitGroup::Default, ""),
+        (-3600.0, SignStyle::Custom, UnitGroup::Default, "(1hr)"),
+        (3600.0, SignStyle::Custom, UnitGroup::Default, "1hr"),
+        (-3600.0, SignStyle::Custom, UnitGroup::Long, "(1 hour)"),
+        (3600.0, SignStyle::Minus, UnitGroup::Default, "1hr"),
+    ];
+
+    for (from_seconds, sign_style, unit_group, expect_str) in expect {
+        let into_duration = FuzzyDuration::new()
+            .set_default_units(unit_group)
+            .set_custom_units(custom_units.to_owned())
+            .set_sign_style(sign_style)
+            .to_duration(from_seconds);
+
+        assert_eq!(into_duration, expect_str);
+    }
+
+    let into_duration = FuzzyDuration::new()
+        .set_custom_units(HashMap::from([
+            (String::from("ago"), String::from("sitten")),
+            (String::from("in"), String::from("")),
+        ]))
+        .set_sign_style(SignStyle::Relative);
+
+    assert_eq!(into_duration.to_duration(-60.0), "1min sitten");
+    assert_eq!(into_duration.to_duration(60.0), "1min");
+}
+
+#[test]
+fn test_to_duration_precision() {
+    let expect: Vec<(f64, usize, Rounding, &str)> = vec![
+        (694861.0, 0, Rounding::Floor, "1w 1d 1hr 1min 1sec"),
+        (694861.0, 2, Rounding::Floor, "1w 1d"),
+        (694861.0, 3, Rounding::Floor, "1w 1d 1hr"),
+        (694861.0, 2, Rounding::HalfUp, "1w 1d"),
+        (694861.0, 2, Rounding::Ceil, "1w 2d"),
+        (738000.0, 2, Rounding::HalfUp, "1w 2d"),
+        (610200.0, 2, Rounding::Floor, "1w 1hr"),
+        (610200.0, 2, Rounding::HalfUp, "1w 2hrs"),
+        (604799.0, 2, Rounding::Floor, "6d 23hrs"),
+        (604799.0, 2, Rounding::Ceil, "1w"),
+        (694861.0, 8, Rounding::Floor, "1w 1d 1hr 1min 1sec"),
+        (1.5, 2, Rounding::Floor, "1sec"),
+        (1.5, 1, Rounding::HalfUp, "2sec"),
+        (0.4, 1, Rounding::HalfUp, ""),
+        (0.4, 1, Rounding::Ceil, "1sec"),
+        (-694861.0, 2, Rounding::Floor, "-1w 1d"),
+        (-694861.0, 2, Rounding::Ceil, "-1w 2d"),
+    ];
+
+    for (from_seconds, precision, rounding, expect_str) in expect {
+        let into_duration = FuzzyDuration::new()
+            .set_precision(precision)
+            .set_rounding(rounding)
+            .to_duration(from_seconds);
+
+        assert_eq!(into_duration, expect_str);
+    }
+
+    let into_duration = FuzzyDuration::new()
+        .set_max_unit("h")
+        .set_min_unit("ms")
+        .set_precision(1)
+        .set_rounding(Rounding::HalfUp);
+
+    assert_eq!(into_duration.to_duration(694861.0), "193hrs");
+    assert_eq!(into_duration.to_duration(1.25), "1sec");
+    assert_eq!(into_duration.to_duration(0.0125), "13ms");
+}
+
+#[test]
+fn test_to_duration_iso() {
+    assert_to_duration(
+        "",
+        "",
+        vec![
+            (0.0, "iso", "PT0S"),
+            (1.0, "iso", "PT1S"),
+            (1.5, "iso", "PT1.5S"),
+            (0.125, "iso", "PT0.125S"),
+            (60.0, "iso", "PT1M"),
+            (3840.0, "iso", "PT1H4M"),
+            (3840.25, "iso", "PT1H4M0.25S"),
+            (86400.0, "iso", "P1D"),
+            (97200.0, "iso", "P1DT3H"),
+            (694861.0, "iso", "P8DT1H1M1S"),
+            (-5400.0, "iso", "-PT1H30M"),
+        ],
+    );
+
+    assert_to_duration("h", "s", vec![(97200.0, "iso", "PT27H")]);
+    assert_to_duration("w", "min", vec![(90061.0, "iso", "P1DT1H1M")]);
+    assert_to_duration("w", "d", vec![(3600.0, "iso", "P0D")]);
+}
+
+#[test]
+fn test_to_seconds_iso() {
+    let expect: Vec<(&str, f64)> = vec![
+        ("PT1H30M", 5400.0),
+        ("P2DT3H", 183600.0),
+        ("P1W", 604800.0),
+        ("PT0S", 0.0),
+        ("PT1.5S", 1.5),
+        ("PT0,5H", 1800.0),
+        ("P1DT1H1M1S", 90061.0),
+        ("-PT15M", -900.0),
+        ("+PT15M", 900.0),
+        ("pt1m", 60.0),
+        (" PT1M ", 60.0),
+    ];
+
+    for (from_string, expect_value) in expect {
+        let result_value = FuzzySeconds::new().to_seconds(from_string);
+        assert_eq!(result_value.unwrap(), expect_value, "{}", from_string);
+    }
+}
+
+#[test]
+fn test_to_seconds_compact() {
+    let expect: Vec<(&str, f64)> = vec![
+        ("1h30m45s", 5445.0),
+        ("250ms", 0.25),
+        ("350ms", 0.35),
+        ("1.5s", 1.5),
+        ("10us", 0.00001),
+        ("10µs", 0.00001),
+        ("500ns", 0.0000005),
+        ("3min2s", 182.0),
+        ("1h 30m", 5400.0),
+        ("2d12h", 216000.0),
+        ("1w", 604800.0),
+        (".5m", 30.0),
+        ("-1m30s", -90.0),
+        ("+90m", 5400.0),
+        // Not compact durations, read as usual
+        ("1 hour", 3600.0),
+        ("twenty minutes", 1200.0),
+    ];
+
+    for (from_string, expect_value) in expect {
+        let result_value = FuzzySeconds::new().set_compact(true).to_seconds(from_string);
+        assert_eq!(result_value.unwrap(), expect_value, "{}", from_string);
+    }
+
+    let expect: Vec<(&str, ParseError)> = vec![
+        ("1m", ParseError::UnsupportedUnit { unit: "months" }),
+        ("250ms", ParseError::UnknownToken { span: 3..5 }),
+        ("99999999999999999999999999999999999w", ParseError::UnknownToken { span: 0..35 }),
+    ];
+
+    for (from_string, expect_error) in expect {
+        let result_value = FuzzySeconds::new().to_seconds(from_string);
+        assert_eq!(result_value, Err(expect_error), "{}", from_string);
+    }
+
+    let expect: Vec<(&str, ParseError)> = vec![
+        ("1y", ParseError::UnsupportedUnit { unit: "years" }),
+        ("1h30", ParseError::UnknownToken { span: 1..4 }),
+        ("99999999999w", ParseError::OutOfRange),
+        ("99999999999999999999999999999999999w", ParseError::OutOfRange),
+        ("340282366920938463463374607431768211w", ParseError::OutOfRange),
+        ("9999999999999999999999999999999999999999w", ParseError::OutOfRange),
+        ("1w 9223372036s", ParseError::OutOfRange),
+    ];
+
+    for (from_string, expect_error) in expect {
+        let result_value = FuzzySeconds::new().set_compact(true).to_seconds(from_string);
+        assert_eq!(result_value, Err(expect_error), "{}", from_string);
+    }
+}
+
 #[test]
 fn test_to_seconds_some() {
     let expect: Vec<(&str, f64)> = vec![
@@ -93,6 +337,17 @@ fn test_to_seconds_some() {
         ("1d 1h 1min -2s", 90058.0),
         ("-1d 1h 1min +2s", -90058.0),
         ("-1d +1h -1min", -82860.0),
+        ("an hour", 3600.0),
+        ("twenty minutes", 1200.0),
+        ("-two days", -172800.0),
+        ("1.5 hours", 5400.0),
+        ("2.5d", 216000.0),
+        ("0.5 minutes", 30.0),
+        ("half an hour", 1800.0),
+        ("an hour and a half", 5400.0),
+        ("1.25s", 1.25),
+        ("2min 3.5s", 123.5),
+        ("0.35 seconds", 0.35),
     ];
 
     for (from_string, expect_value) in expect {
@@ -116,6 +371,9 @@ fn test_to_seconds_none() {
         "1m",
         "+1 month",
         "-2 months",
+        "twenty",
+        "1.5 months",
+        "half a year",
     ];
 
     for from_string in expect {
@@ -124,6 +382,32 @@ fn test_to_seconds_none() {
     }
 }
 
+#[test]
+fn test_to_seconds_errors() {
+    let expect: Vec<(&str, ParseError)> = vec![
+        ("last week", ParseError::NoMatchingPattern),
+        ("1 hour foo", ParseError::UnknownToken { span: 7..10 }),
+        ("+1 year", ParseError::UnsupportedUnit { unit: "years" }),
+        ("1m 2w 30min", ParseError::UnsupportedUnit { unit: "months" }),
+        ("1 quarter", ParseError::UnsupportedUnit { unit: "quarters" }),
+        ("2 weekends", ParseError::UnsupportedUnit { unit: "weekends" }),
+        ("3 business days", ParseError::UnsupportedUnit { unit: "business days" }),
+        ("P1Y", ParseError::UnsupportedUnit { unit: "years" }),
+        ("P1M", ParseError::UnsupportedUnit { unit: "months" }),
+        ("PT", ParseError::NoMatchingPattern),
+        ("P1DT", ParseError::NoMatchingPattern),
+        ("PT1H2", ParseError::NoMatchingPattern),
+        ("PT1M1H", ParseError::NoMatchingPattern),
+        ("P1H", ParseError::NoMatchingPattern),
+        ("PTH", ParseError::NoMatchingPattern),
+    ];
+
+    for (from_string, expect_error) in expect {
+        let result_value = FuzzySeconds::new().to_seconds(from_string);
+        assert_eq!(result_value, Err(expect_error));
+    }
+}
+
 fn assert_to_duration(max: &str, min: &str, expect: Vec<(f64, &str, &str)>) {
     for (from_seconds, unit_group, expect_str) in expect {
         let into_duration = FuzzyDuration::new()
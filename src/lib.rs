@@ -4,14 +4,16 @@ use chrono::{DateTime, FixedOffset, NaiveDate};
 use fuzzy_date_rs::FuzzyDuration;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyDate, PyDateTime};
+use pyo3::types::{PyDate, PyDateTime, PyDict, PyList};
 use std::collections::HashMap;
 
 #[pymodule]
 mod fuzzydate {
     use super::*;
     use crate::fuzzydate::__core__::Config;
-    use fuzzy_date_rs::token::{Token, UnitNames, UnitGroup, WeekStartDay};
+    use fuzzy_date_rs::calendar::HolidayDates;
+    use fuzzy_date_rs::holiday::HolidayRules;
+    use fuzzy_date_rs::token::{DayPeriod, PhraseNames, Token, UnitNames, UnitGroup, WeekStartDay};
     use fuzzy_date_rs::{FuzzyDate, FuzzySeconds};
 
     const ATTR_CONFIG: &'static str = "config";
@@ -19,7 +21,8 @@ mod fuzzydate {
     #[pymodule]
     mod __core__ {
         use super::*;
-        use fuzzy_date_rs::token::Token;
+        use fuzzy_date_rs::holiday::HolidayRules;
+        use fuzzy_date_rs::token::{DayPeriod, Token};
 
         #[pyclass]
         pub(crate) struct Config {
@@ -29,6 +32,21 @@ mod fuzzydate {
             #[pyo3(get)]
             pub(crate) tokens: HashMap<String, u32>,
 
+            #[pyo3(get)]
+            pub(crate) day_periods: HashMap<String, String>,
+
+            #[pyo3(get)]
+            pub(crate) fiscal_year_start: u32,
+
+            #[pyo3(get, set)]
+            pub(crate) holidays: Vec<NaiveDate>,
+
+            #[pyo3(get)]
+            pub(crate) holiday_locale: String,
+
+            #[pyo3(get, set)]
+            pub(crate) phrases: HashMap<String, String>,
+
             #[pyo3(get, set)]
             pub(crate) units: HashMap<String, String>,
 
@@ -37,6 +55,9 @@ mod fuzzydate {
 
             #[pyo3(get, set)]
             pub(crate) units_short: HashMap<String, String>,
+
+            #[pyo3(get)]
+            pub(crate) weekend_days: Vec<u32>,
         }
 
         #[pymethods]
@@ -108,6 +129,94 @@ mod fuzzydate {
 
                 Ok(())
             }
+
+            /// Set times of day that named periods, e.g. "morning" or "EOD", resolve into
+            ///
+            /// Accepted periods are "morning", "noon", "afternoon", "evening", "tonight"
+            /// and "eod", with times given as "HH:MM". Periods are merged with any
+            /// previously set ones. Raises a ValueError if the period does not exist
+            /// or if the time of day is not valid.
+            ///
+            /// :param periods: Map of period names and times of day, e.g. {"morning": "08:30"}
+            /// :type source: dict[str, str]
+            /// :raises ValueError
+            /// :rtype None
+            ///
+            #[pyo3(text_signature = "(periods: dict[str, str]) -> None")]
+            fn add_day_periods(&mut self, periods: HashMap<String, String>) -> PyResult<()> {
+                for (period, time) in periods {
+                    if period.parse::<DayPeriod>().is_err() {
+                        return Err(PyValueError::new_err(format!("Period \"{}\" does not exist", period)));
+                    }
+
+                    if python::into_hour_minute(&time).is_none() {
+                        return Err(PyValueError::new_err(format!(
+                            "Period \"{}\" time \"{}\" is not valid",
+                            period, time,
+                        )));
+                    }
+
+                    self.day_periods.insert(period.to_lowercase(), time);
+                }
+
+                Ok(())
+            }
+
+            /// Set the month that fiscal years start from, as a number from 1 to 12
+            ///
+            /// Fiscal years are named after the calendar year they end in, e.g. with
+            /// fiscal years starting from July, FY2025 begins on July 1st 2024. Raises
+            /// a ValueError if the month does not exist.
+            ///
+            /// :raises ValueError
+            #[setter]
+            fn set_fiscal_year_start(&mut self, month: u32) -> PyResult<()> {
+                if month.lt(&1) || month.gt(&12) {
+                    return Err(PyValueError::new_err(format!("Month {} does not exist", month)));
+                }
+
+                self.fiscal_year_start = month;
+                Ok(())
+            }
+
+            /// Set country whose named holidays, e.g. "Thanksgiving", are recognized,
+            /// as a two-letter country code
+            ///
+            /// Holidays default to "us", with "gb" and "ca" also available. Raises
+            /// a ValueError if the country has no holidays defined.
+            ///
+            /// :raises ValueError
+            #[setter]
+            fn set_holiday_locale(&mut self, locale: &str) -> PyResult<()> {
+                if HolidayRules::from_locale(locale).is_none() {
+                    return Err(PyValueError::new_err(format!("Holidays for \"{}\" are not defined", locale)));
+                }
+
+                self.holiday_locale = locale.to_lowercase();
+                Ok(())
+            }
+
+            /// Set weekdays that make up the weekend, as numbers from 1 for Monday
+            /// to 7 for Sunday
+            ///
+            /// Weekend defaults to Saturday and Sunday, e.g. [5, 6] would use Friday
+            /// and Saturday instead. Raises a ValueError if a weekday does not exist,
+            /// or if the weekdays do not follow one another.
+            ///
+            /// :raises ValueError
+            #[setter]
+            fn set_weekend_days(&mut self, weekdays: Vec<u32>) -> PyResult<()> {
+                if let Some(weekday) = weekdays.iter().find(|v| v.lt(&&1) || v.gt(&&7)) {
+                    return Err(PyValueError::new_err(format!("Weekday {} does not exist", weekday)));
+                }
+
+                if !python::into_weekend_days(&weekdays).is_contiguous() {
+                    return Err(PyValueError::new_err("Weekend days must follow one another"));
+                }
+
+                self.weekend_days = weekdays;
+                Ok(())
+            }
         }
     }
 
@@ -127,6 +236,31 @@ mod fuzzydate {
         #[classattr]
         const TOMORROW: &'static str = fuzzy_date_rs::pattern::PATTERN_TOMORROW;
 
+        #[classattr]
+        const NOON: &'static str = fuzzy_date_rs::pattern::PATTERN_NOON;
+        #[classattr]
+        const MIDDAY: &'static str = fuzzy_date_rs::pattern::PATTERN_MIDDAY;
+        #[classattr]
+        const MORNING: &'static str = fuzzy_date_rs::pattern::PATTERN_MORNING;
+        #[classattr]
+        const THIS_MORNING: &'static str = fuzzy_date_rs::pattern::PATTERN_THIS_MORNING;
+        #[classattr]
+        const AFTERNOON: &'static str = fuzzy_date_rs::pattern::PATTERN_AFTERNOON;
+        #[classattr]
+        const THIS_AFTERNOON: &'static str = fuzzy_date_rs::pattern::PATTERN_THIS_AFTERNOON;
+        #[classattr]
+        const EVENING: &'static str = fuzzy_date_rs::pattern::PATTERN_EVENING;
+        #[classattr]
+        const THIS_EVENING: &'static str = fuzzy_date_rs::pattern::PATTERN_THIS_EVENING;
+        #[classattr]
+        const TONIGHT: &'static str = fuzzy_date_rs::pattern::PATTERN_TONIGHT;
+        #[classattr]
+        const EOD: &'static str = fuzzy_date_rs::pattern::PATTERN_EOD;
+        #[classattr]
+        const END_OF_BUSINESS: &'static str = fuzzy_date_rs::pattern::PATTERN_END_OF_BUSINESS;
+        #[classattr]
+        const CLOSE_OF_BUSINESS: &'static str = fuzzy_date_rs::pattern::PATTERN_CLOSE_OF_BUSINESS;
+
         #[classattr]
         const THIS_WDAY: &'static str = fuzzy_date_rs::pattern::PATTERN_THIS_WDAY;
         #[classattr]
@@ -141,6 +275,28 @@ mod fuzzydate {
         #[classattr]
         const NEXT_MONTH: &'static str = fuzzy_date_rs::pattern::PATTERN_NEXT_MONTH;
 
+        #[classattr]
+        const HOLIDAY: &'static str = fuzzy_date_rs::pattern::PATTERN_HOLIDAY;
+        #[classattr]
+        const HOLIDAY_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_HOLIDAY_YEAR;
+        #[classattr]
+        const THIS_HOLIDAY: &'static str = fuzzy_date_rs::pattern::PATTERN_THIS_HOLIDAY;
+        #[classattr]
+        const PREV_HOLIDAY: &'static str = fuzzy_date_rs::pattern::PATTERN_PREV_HOLIDAY;
+        #[classattr]
+        const LAST_HOLIDAY: &'static str = fuzzy_date_rs::pattern::PATTERN_LAST_HOLIDAY;
+        #[classattr]
+        const NEXT_HOLIDAY: &'static str = fuzzy_date_rs::pattern::PATTERN_NEXT_HOLIDAY;
+        #[classattr]
+        const LONG_UNIT_BEFORE_HOLIDAY: &'static str = fuzzy_date_rs::pattern::PATTERN_LONG_UNIT_BEFORE_HOLIDAY;
+        #[classattr]
+        const LONG_UNIT_BEFORE_HOLIDAY_YEAR: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_LONG_UNIT_BEFORE_HOLIDAY_YEAR;
+        #[classattr]
+        const LONG_UNIT_AFTER_HOLIDAY: &'static str = fuzzy_date_rs::pattern::PATTERN_LONG_UNIT_AFTER_HOLIDAY;
+        #[classattr]
+        const LONG_UNIT_AFTER_HOLIDAY_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_LONG_UNIT_AFTER_HOLIDAY_YEAR;
+
         #[classattr]
         const THIS_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_THIS_LONG_UNIT;
         #[classattr]
@@ -170,6 +326,38 @@ mod fuzzydate {
         const UNIT_AGO: &'static str = fuzzy_date_rs::pattern::PATTERN_UNIT_AGO;
         #[classattr]
         const LONG_UNIT_AGO: &'static str = fuzzy_date_rs::pattern::PATTERN_LONG_UNIT_AGO;
+        #[classattr]
+        const IN_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_IN_UNIT;
+        #[classattr]
+        const IN_SHORT_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_IN_SHORT_UNIT;
+        #[classattr]
+        const IN_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_IN_LONG_UNIT;
+        #[classattr]
+        const WITHIN_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_WITHIN_UNIT;
+        #[classattr]
+        const WITHIN_SHORT_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_WITHIN_SHORT_UNIT;
+        #[classattr]
+        const WITHIN_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_WITHIN_LONG_UNIT;
+        #[classattr]
+        const UNIT_FROM_NOW: &'static str = fuzzy_date_rs::pattern::PATTERN_UNIT_FROM_NOW;
+        #[classattr]
+        const SHORT_UNIT_FROM_NOW: &'static str = fuzzy_date_rs::pattern::PATTERN_SHORT_UNIT_FROM_NOW;
+        #[classattr]
+        const LONG_UNIT_FROM_NOW: &'static str = fuzzy_date_rs::pattern::PATTERN_LONG_UNIT_FROM_NOW;
+        #[classattr]
+        const UNIT_HENCE: &'static str = fuzzy_date_rs::pattern::PATTERN_UNIT_HENCE;
+        #[classattr]
+        const SHORT_UNIT_HENCE: &'static str = fuzzy_date_rs::pattern::PATTERN_SHORT_UNIT_HENCE;
+        #[classattr]
+        const LONG_UNIT_HENCE: &'static str = fuzzy_date_rs::pattern::PATTERN_LONG_UNIT_HENCE;
+        #[classattr]
+        const HALF_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_HALF_LONG_UNIT;
+        #[classattr]
+        const HALF_LONG_UNIT_AGO: &'static str = fuzzy_date_rs::pattern::PATTERN_HALF_LONG_UNIT_AGO;
+        #[classattr]
+        const LONG_UNIT_AND_HALF: &'static str = fuzzy_date_rs::pattern::PATTERN_LONG_UNIT_AND_HALF;
+        #[classattr]
+        const LONG_UNIT_AND_HALF_AGO: &'static str = fuzzy_date_rs::pattern::PATTERN_LONG_UNIT_AND_HALF_AGO;
 
         #[classattr]
         const FIRST_LONG_UNIT_OF_MONTH: &'static str = fuzzy_date_rs::pattern::PATTERN_FIRST_LONG_UNIT_OF_MONTH;
@@ -189,9 +377,15 @@ mod fuzzydate {
         const FIRST_LONG_UNIT_OF_THIS_LONG_UNIT: &'static str =
             fuzzy_date_rs::pattern::PATTERN_FIRST_LONG_UNIT_OF_THIS_LONG_UNIT;
         #[classattr]
+        const FIRST_LONG_UNIT_OF_THE_LONG_UNIT: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_FIRST_LONG_UNIT_OF_THE_LONG_UNIT;
+        #[classattr]
         const LAST_LONG_UNIT_OF_THIS_LONG_UNIT: &'static str =
             fuzzy_date_rs::pattern::PATTERN_LAST_LONG_UNIT_OF_THIS_LONG_UNIT;
         #[classattr]
+        const LAST_LONG_UNIT_OF_THE_LONG_UNIT: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_LAST_LONG_UNIT_OF_THE_LONG_UNIT;
+        #[classattr]
         const FIRST_LONG_UNIT_OF_PREV_LONG_UNIT: &'static str =
             fuzzy_date_rs::pattern::PATTERN_FIRST_LONG_UNIT_OF_PREV_LONG_UNIT;
         #[classattr]
@@ -216,6 +410,77 @@ mod fuzzydate {
         const LAST_WDAY_OF_MONTH_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_LAST_WDAY_OF_MONTH_YEAR;
         #[classattr]
         const LAST_WDAY_OF_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_LAST_WDAY_OF_YEAR;
+        #[classattr]
+        const FIRST_WDAY_OF_THIS_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_FIRST_WDAY_OF_THIS_LONG_UNIT;
+        #[classattr]
+        const FIRST_WDAY_OF_PREV_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_FIRST_WDAY_OF_PREV_LONG_UNIT;
+        #[classattr]
+        const FIRST_WDAY_OF_NEXT_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_FIRST_WDAY_OF_NEXT_LONG_UNIT;
+        #[classattr]
+        const LAST_WDAY_OF_THIS_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_LAST_WDAY_OF_THIS_LONG_UNIT;
+        #[classattr]
+        const LAST_WDAY_OF_PREV_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_LAST_WDAY_OF_PREV_LONG_UNIT;
+        #[classattr]
+        const LAST_WDAY_OF_NEXT_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_LAST_WDAY_OF_NEXT_LONG_UNIT;
+
+        #[classattr]
+        const NTH_WDAY_OF_MONTH: &'static str = fuzzy_date_rs::pattern::PATTERN_NTH_WDAY_OF_MONTH;
+        #[classattr]
+        const NTH_WDAY_OF_MONTH_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_NTH_WDAY_OF_MONTH_YEAR;
+        #[classattr]
+        const NTH_WDAY_OF_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_NTH_WDAY_OF_YEAR;
+        #[classattr]
+        const NTH_WDAY_OF_THIS_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_NTH_WDAY_OF_THIS_LONG_UNIT;
+        #[classattr]
+        const NTH_WDAY_OF_PREV_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_NTH_WDAY_OF_PREV_LONG_UNIT;
+        #[classattr]
+        const NTH_WDAY_OF_NEXT_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_NTH_WDAY_OF_NEXT_LONG_UNIT;
+        #[classattr]
+        const NTH_LAST_WDAY_OF_MONTH: &'static str = fuzzy_date_rs::pattern::PATTERN_NTH_LAST_WDAY_OF_MONTH;
+        #[classattr]
+        const NTH_LAST_WDAY_OF_MONTH_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_NTH_LAST_WDAY_OF_MONTH_YEAR;
+        #[classattr]
+        const NTH_LAST_WDAY_OF_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_NTH_LAST_WDAY_OF_YEAR;
+        #[classattr]
+        const NTH_LAST_WDAY_OF_THIS_LONG_UNIT: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_NTH_LAST_WDAY_OF_THIS_LONG_UNIT;
+        #[classattr]
+        const NTH_LAST_WDAY_OF_PREV_LONG_UNIT: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_NTH_LAST_WDAY_OF_PREV_LONG_UNIT;
+        #[classattr]
+        const NTH_LAST_WDAY_OF_NEXT_LONG_UNIT: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_NTH_LAST_WDAY_OF_NEXT_LONG_UNIT;
+        #[classattr]
+        const THE_NTH_WDAY_OF_MONTH: &'static str = fuzzy_date_rs::pattern::PATTERN_THE_NTH_WDAY_OF_MONTH;
+        #[classattr]
+        const THE_NTH_WDAY_OF_MONTH_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_THE_NTH_WDAY_OF_MONTH_YEAR;
+        #[classattr]
+        const THE_NTH_WDAY_OF_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_THE_NTH_WDAY_OF_YEAR;
+        #[classattr]
+        const THE_NTH_WDAY_OF_THIS_LONG_UNIT: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_THE_NTH_WDAY_OF_THIS_LONG_UNIT;
+        #[classattr]
+        const THE_NTH_WDAY_OF_PREV_LONG_UNIT: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_THE_NTH_WDAY_OF_PREV_LONG_UNIT;
+        #[classattr]
+        const THE_NTH_WDAY_OF_NEXT_LONG_UNIT: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_THE_NTH_WDAY_OF_NEXT_LONG_UNIT;
+        #[classattr]
+        const THE_NTH_LAST_WDAY_OF_MONTH: &'static str = fuzzy_date_rs::pattern::PATTERN_THE_NTH_LAST_WDAY_OF_MONTH;
+        #[classattr]
+        const THE_NTH_LAST_WDAY_OF_MONTH_YEAR: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_THE_NTH_LAST_WDAY_OF_MONTH_YEAR;
+        #[classattr]
+        const THE_NTH_LAST_WDAY_OF_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_THE_NTH_LAST_WDAY_OF_YEAR;
+        #[classattr]
+        const THE_NTH_LAST_WDAY_OF_THIS_LONG_UNIT: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_THE_NTH_LAST_WDAY_OF_THIS_LONG_UNIT;
+        #[classattr]
+        const THE_NTH_LAST_WDAY_OF_PREV_LONG_UNIT: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_THE_NTH_LAST_WDAY_OF_PREV_LONG_UNIT;
+        #[classattr]
+        const THE_NTH_LAST_WDAY_OF_NEXT_LONG_UNIT: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_THE_NTH_LAST_WDAY_OF_NEXT_LONG_UNIT;
 
         #[classattr]
         const TIMESTAMP: &'static str = fuzzy_date_rs::pattern::PATTERN_TIMESTAMP;
@@ -246,6 +511,42 @@ mod fuzzydate {
         #[classattr]
         const DATE_NTH_MONTH_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_DATE_NTH_MONTH_YEAR;
 
+        #[classattr]
+        const QUARTER: &'static str = fuzzy_date_rs::pattern::PATTERN_QUARTER;
+        #[classattr]
+        const QUARTER_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_QUARTER_YEAR;
+        #[classattr]
+        const YEAR_QUARTER: &'static str = fuzzy_date_rs::pattern::PATTERN_YEAR_QUARTER;
+
+        #[classattr]
+        const THIS_FISCAL_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_THIS_FISCAL_LONG_UNIT;
+        #[classattr]
+        const PREV_FISCAL_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_PREV_FISCAL_LONG_UNIT;
+        #[classattr]
+        const LAST_FISCAL_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_LAST_FISCAL_LONG_UNIT;
+        #[classattr]
+        const NEXT_FISCAL_LONG_UNIT: &'static str = fuzzy_date_rs::pattern::PATTERN_NEXT_FISCAL_LONG_UNIT;
+        #[classattr]
+        const FISCAL_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_FISCAL_YEAR;
+        #[classattr]
+        const FISCAL_SHORT_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_FISCAL_SHORT_YEAR;
+        #[classattr]
+        const QUARTER_FISCAL_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_QUARTER_FISCAL_YEAR;
+        #[classattr]
+        const QUARTER_FISCAL_SHORT_YEAR: &'static str = fuzzy_date_rs::pattern::PATTERN_QUARTER_FISCAL_SHORT_YEAR;
+        #[classattr]
+        const FIRST_LONG_UNIT_OF_FISCAL_YEAR: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_FIRST_LONG_UNIT_OF_FISCAL_YEAR;
+        #[classattr]
+        const LAST_LONG_UNIT_OF_FISCAL_YEAR: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_LAST_LONG_UNIT_OF_FISCAL_YEAR;
+        #[classattr]
+        const FIRST_LONG_UNIT_OF_THIS_FISCAL_LONG_UNIT: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_FIRST_LONG_UNIT_OF_THIS_FISCAL_LONG_UNIT;
+        #[classattr]
+        const LAST_LONG_UNIT_OF_THIS_FISCAL_LONG_UNIT: &'static str =
+            fuzzy_date_rs::pattern::PATTERN_LAST_LONG_UNIT_OF_THIS_FISCAL_LONG_UNIT;
+
         #[classattr]
         const DATETIME_YMD_HMS: &'static str = fuzzy_date_rs::pattern::PATTERN_DATETIME_YMD_HMS;
         #[classattr]
@@ -323,6 +624,8 @@ mod fuzzydate {
         const SHORT_UNIT_MONTH: i16 = fuzzy_date_rs::pattern::TOKEN_SHORT_UNIT_MONTH;
         #[classattr]
         const SHORT_UNIT_YEAR: i16 = fuzzy_date_rs::pattern::TOKEN_SHORT_UNIT_YEAR;
+        #[classattr]
+        const SHORT_UNIT_QUARTER: i16 = fuzzy_date_rs::pattern::TOKEN_SHORT_UNIT_QUARTER;
 
         #[classattr]
         const LONG_UNIT_SEC: i16 = fuzzy_date_rs::pattern::TOKEN_LONG_UNIT_SEC;
@@ -338,6 +641,12 @@ mod fuzzydate {
         const LONG_UNIT_MONTH: i16 = fuzzy_date_rs::pattern::TOKEN_LONG_UNIT_MONTH;
         #[classattr]
         const LONG_UNIT_YEAR: i16 = fuzzy_date_rs::pattern::TOKEN_LONG_UNIT_YEAR;
+        #[classattr]
+        const LONG_UNIT_QUARTER: i16 = fuzzy_date_rs::pattern::TOKEN_LONG_UNIT_QUARTER;
+        #[classattr]
+        const LONG_UNIT_WEEKEND: i16 = fuzzy_date_rs::pattern::TOKEN_LONG_UNIT_WEEKEND;
+        #[classattr]
+        const LONG_UNIT_BUSINESS_DAY: i16 = fuzzy_date_rs::pattern::TOKEN_LONG_UNIT_BUSINESS_DAY;
 
         #[classattr]
         const MERIDIEM_AM: i16 = fuzzy_date_rs::pattern::TOKEN_MERIDIEM_AM;
@@ -345,11 +654,48 @@ mod fuzzydate {
         const MERIDIEM_PM: i16 = fuzzy_date_rs::pattern::TOKEN_MERIDIEM_PM;
     }
 
+    #[pyclass(name = "phrase")]
+    pub(crate) struct Phrases {}
+
+    #[pymethods]
+    impl Phrases {
+        #[classattr]
+        const A_MINUTE: &'static str = fuzzy_date_rs::pattern::PHRASE_A_MINUTE;
+        #[classattr]
+        const AT: &'static str = fuzzy_date_rs::pattern::PHRASE_AT;
+        #[classattr]
+        const JUST_NOW: &'static str = fuzzy_date_rs::pattern::PHRASE_JUST_NOW;
+        #[classattr]
+        const LAST: &'static str = fuzzy_date_rs::pattern::PHRASE_LAST;
+        #[classattr]
+        const LAST_WEEK: &'static str = fuzzy_date_rs::pattern::PHRASE_LAST_WEEK;
+        #[classattr]
+        const MONTH: &'static str = fuzzy_date_rs::pattern::PHRASE_MONTH;
+        #[classattr]
+        const MONTHS: &'static str = fuzzy_date_rs::pattern::PHRASE_MONTHS;
+        #[classattr]
+        const NEXT: &'static str = fuzzy_date_rs::pattern::PHRASE_NEXT;
+        #[classattr]
+        const NEXT_WEEK: &'static str = fuzzy_date_rs::pattern::PHRASE_NEXT_WEEK;
+        #[classattr]
+        const TODAY: &'static str = fuzzy_date_rs::pattern::PHRASE_TODAY;
+        #[classattr]
+        const TOMORROW: &'static str = fuzzy_date_rs::pattern::PHRASE_TOMORROW;
+        #[classattr]
+        const YEAR: &'static str = fuzzy_date_rs::pattern::PHRASE_YEAR;
+        #[classattr]
+        const YEARS: &'static str = fuzzy_date_rs::pattern::PHRASE_YEARS;
+        #[classattr]
+        const YESTERDAY: &'static str = fuzzy_date_rs::pattern::PHRASE_YESTERDAY;
+    }
+
     #[pyclass(name = "unit")]
     pub(crate) struct Units {}
 
     #[pymethods]
     impl Units {
+        #[classattr]
+        const AGO: &'static str = fuzzy_date_rs::pattern::UNIT_AGO;
         #[classattr]
         const DAY: &'static str = fuzzy_date_rs::pattern::UNIT_DAY;
         #[classattr]
@@ -359,10 +705,24 @@ mod fuzzydate {
         #[classattr]
         const HOURS: &'static str = fuzzy_date_rs::pattern::UNIT_HOURS;
         #[classattr]
+        const IN: &'static str = fuzzy_date_rs::pattern::UNIT_IN;
+        #[classattr]
+        const MICROSECOND: &'static str = fuzzy_date_rs::pattern::UNIT_MICROSECOND;
+        #[classattr]
+        const MICROSECONDS: &'static str = fuzzy_date_rs::pattern::UNIT_MICROSECONDS;
+        #[classattr]
+        const MILLISECOND: &'static str = fuzzy_date_rs::pattern::UNIT_MILLISECOND;
+        #[classattr]
+        const MILLISECONDS: &'static str = fuzzy_date_rs::pattern::UNIT_MILLISECONDS;
+        #[classattr]
         const MINUTE: &'static str = fuzzy_date_rs::pattern::UNIT_MINUTE;
         #[classattr]
         const MINUTES: &'static str = fuzzy_date_rs::pattern::UNIT_MINUTES;
         #[classattr]
+        const NEGATIVE_PREFIX: &'static str = fuzzy_date_rs::pattern::UNIT_NEGATIVE_PREFIX;
+        #[classattr]
+        const NEGATIVE_SUFFIX: &'static str = fuzzy_date_rs::pattern::UNIT_NEGATIVE_SUFFIX;
+        #[classattr]
         const SECOND: &'static str = fuzzy_date_rs::pattern::UNIT_SECOND;
         #[classattr]
         const SECONDS: &'static str = fuzzy_date_rs::pattern::UNIT_SECONDS;
@@ -384,14 +744,21 @@ mod fuzzydate {
     /// :type today: datetime.date, optional
     /// :param weekday_start_mon: Whether weeks begin on Monday instead of Sunday. Defaults to True.
     /// :type weekday_start_mon: bool, optional, default True
+    /// :param date_order: Order of day, month and year in numeric dates. Possible values are
+    ///                    "dmy", "mdy", "ymd" and None. Defaults to None, which uses the only
+    ///                    valid reading, or the separator's own order if both are valid.
+    /// :type date_order: str, optional
+    /// :param year_pivot: Two-digit years below this are in the 2000s and others in the 1900s.
+    ///                    Defaults to 50. If None, the year closest to current time is used.
+    /// :type year_pivot: int, optional, default 50
     /// :raises ValueError
     /// :rtype datetime.date
     ///
     #[pyfunction]
     #[pyo3(
         pass_module,
-        signature = (source, today=None, weekday_start_mon=true),
-        text_signature = "(source: str, today: datetime.date = None, weekday_start_mon: bool = True) -> datetime.date"
+        signature = (source, today=None, weekday_start_mon=true, date_order=None, year_pivot=Some(50)),
+        text_signature = "(source: str, today: datetime.date = None, weekday_start_mon: bool = True, date_order: str = None, year_pivot: int = 50) -> datetime.date"
     )]
     fn to_date(
         module: &Bound<'_, PyModule>,
@@ -399,28 +766,21 @@ mod fuzzydate {
         source: &str,
         today: Option<Bound<PyDate>>,
         weekday_start_mon: bool,
+        date_order: Option<&str>,
+        year_pivot: Option<u8>,
     ) -> PyResult<NaiveDate> {
-        let date_value = &python::into_date(py, today)?;
-        let config_patterns = read_config(module)?.patterns;
-        let config_tokens = read_tokens(module)?;
+        let date_value = python::into_date(py, today)?;
+        let fuzzy_date = build_fuzzy_date(module, date_value, weekday_start_mon)?
+            .set_date_order(python::into_choice(date_order, "Date order")?)
+            .set_two_digit_year(python::into_two_digit_year(year_pivot));
 
         py.detach(move || {
-            let week_start_day = match weekday_start_mon {
-                true => WeekStartDay::Monday,
-                false => WeekStartDay::Sunday,
-            };
+            let result = fuzzy_date.to_datetime(source);
 
-            let result = FuzzyDate::from_time(date_value.to_owned())
-                .set_first_weekday(week_start_day)
-                .set_custom_patterns(config_patterns)
-                .set_custom_tokens(config_tokens)
-                .to_datetime(source);
-
-            if let Some(v) = result {
-                return Ok(v.date_naive());
+            match result {
+                Ok(v) => Ok(v.date_naive()),
+                Err(e) => Err(python::into_error(e, source, "date")),
             }
-
-            Err(PyValueError::new_err(format!("Unable to convert \"{}\" into datetime", source)))
         })
     }
 
@@ -436,14 +796,21 @@ mod fuzzydate {
     /// :type now: datetime.datetime, optional
     /// :param weekday_start_mon: Whether weeks begin on Monday instead of Sunday. Defaults to True.
     /// :type weekday_start_mon: bool, optional, default True
+    /// :param date_order: Order of day, month and year in numeric dates. Possible values are
+    ///                    "dmy", "mdy", "ymd" and None. Defaults to None, which uses the only
+    ///                    valid reading, or the separator's own order if both are valid.
+    /// :type date_order: str, optional
+    /// :param year_pivot: Two-digit years below this are in the 2000s and others in the 1900s.
+    ///                    Defaults to 50. If None, the year closest to current time is used.
+    /// :type year_pivot: int, optional, default 50
     /// :raises ValueError
     /// :rtype datetime.datetime
     ///
     #[pyfunction]
     #[pyo3(
         pass_module,
-        signature = (source, now=None, weekday_start_mon=true),
-        text_signature = "(source: str, now: datetime.datetime = None, weekday_start_mon: bool = True) -> datetime.datetime"
+        signature = (source, now=None, weekday_start_mon=true, date_order=None, year_pivot=Some(50)),
+        text_signature = "(source: str, now: datetime.datetime = None, weekday_start_mon: bool = True, date_order: str = None, year_pivot: int = 50) -> datetime.datetime"
     )]
     fn to_datetime(
         module: &Bound<'_, PyModule>,
@@ -451,61 +818,219 @@ mod fuzzydate {
         source: &str,
         now: Option<Bound<PyDateTime>>,
         weekday_start_mon: bool,
+        date_order: Option<&str>,
+        year_pivot: Option<u8>,
     ) -> PyResult<DateTime<FixedOffset>> {
-        let date_value = &python::into_datetime(py, now)?;
-        let config_patterns = read_config(module)?.patterns;
-        let config_tokens = read_tokens(module)?;
+        let date_value = python::into_datetime(py, now)?;
+        let fuzzy_date = build_fuzzy_date(module, date_value, weekday_start_mon)?
+            .set_date_order(python::into_choice(date_order, "Date order")?)
+            .set_two_digit_year(python::into_two_digit_year(year_pivot));
 
         py.detach(move || {
-            let week_start_day = match weekday_start_mon {
-                true => WeekStartDay::Monday,
-                false => WeekStartDay::Sunday,
-            };
+            let result = fuzzy_date.to_datetime(source);
 
-            let result = FuzzyDate::from_time(date_value.to_owned())
-                .set_first_weekday(week_start_day)
-                .set_custom_patterns(config_patterns)
-                .set_custom_tokens(config_tokens)
-                .to_datetime(source);
+            match result {
+                Ok(v) => Ok(v),
+                Err(e) => Err(python::into_error(e, source, "datetime")),
+            }
+        })
+    }
 
-            if let Some(v) = result {
-                return Ok(v);
+    /// Describe how a source string is converted into a datetime
+    ///
+    /// Returns a dictionary with the token pattern of the source string,
+    /// the matched patterns in order with the datetime after each of them,
+    /// or the error message of the pattern that failed, rules used for the
+    /// conversion and the final result or error message.
+    ///
+    /// :param source: Source string
+    /// :type source: str
+    /// :param now: Current time. Defaults to system time in UTC.
+    /// :type now: datetime.datetime, optional
+    /// :param weekday_start_mon: Whether weeks begin on Monday instead of Sunday. Defaults to True.
+    /// :type weekday_start_mon: bool, optional, default True
+    /// :param date_order: Order of day, month and year in numeric dates. Possible values are
+    ///                    "dmy", "mdy", "ymd" and None. Defaults to None, which uses the only
+    ///                    valid reading, or the separator's own order if both are valid.
+    /// :type date_order: str, optional
+    /// :param year_pivot: Two-digit years below this are in the 2000s and others in the 1900s.
+    ///                    Defaults to 50. If None, the year closest to current time is used.
+    /// :type year_pivot: int, optional, default 50
+    /// :raises ValueError
+    /// :rtype dict
+    ///
+    #[pyfunction]
+    #[pyo3(
+        pass_module,
+        signature = (source, now=None, weekday_start_mon=true, date_order=None, year_pivot=Some(50)),
+        text_signature = "(source: str, now: datetime.datetime = None, weekday_start_mon: bool = True, date_order: str = None, year_pivot: int = 50) -> dict"
+    )]
+    fn explain<'py>(
+        module: &Bound<'py, PyModule>,
+        py: Python<'py>,
+        source: &str,
+        now: Option<Bound<PyDateTime>>,
+        weekday_start_mon: bool,
+        date_order: Option<&str>,
+        year_pivot: Option<u8>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let date_value = python::into_datetime(py, now)?;
+        let fuzzy_date = build_fuzzy_date(module, date_value, weekday_start_mon)?
+            .set_date_order(python::into_choice(date_order, "Date order")?)
+            .set_two_digit_year(python::into_two_digit_year(year_pivot));
+
+        let explanation = py.detach(move || fuzzy_date.explain(source));
+
+        let steps = PyList::empty(py);
+
+        for step in &explanation.steps {
+            let step_dict = PyDict::new(py);
+            step_dict.set_item("pattern", format!("{:?}", step.pattern))?;
+            step_dict.set_item("match", &step.matched)?;
+            step_dict.set_item("datetime", step.time)?;
+            step_dict.set_item("error", step.error.as_ref().map(|e| e.to_string()))?;
+            steps.append(step_dict)?;
+        }
+
+        let rules = PyDict::new(py);
+        rules.set_item("date_years", explanation.date_years)?;
+        rules.set_item("reset_time", explanation.reset_time)?;
+        rules.set_item("weekday_start_mon", explanation.week_start_mon)?;
+
+        let result = PyDict::new(py);
+        result.set_item("pattern", &explanation.pattern)?;
+        result.set_item("steps", steps)?;
+        result.set_item("rules", rules)?;
+
+        match &explanation.result {
+            Ok(v) => {
+                result.set_item("datetime", v)?;
+                result.set_item("error", py.None())?;
             }
+            Err(e) => {
+                result.set_item("datetime", py.None())?;
+                result.set_item("error", e.to_string())?;
+            }
+        }
 
-            Err(PyValueError::new_err(format!("Unable to convert \"{}\" into datetime", source)))
-        })
+        Ok(result)
+    }
+
+    /// Describe datetime as a calendar phrase, e.g. "Yesterday at 14:00" or "Last Friday"
+    ///
+    /// Current time (`now`) defaults to system time in UTC. Datetimes that do not
+    /// contain a timezone are assumed to be in UTC. Dates outside of the previous,
+    /// current and next week are shown as "April 1", or as "April 1, 2022" for other
+    /// years. Weekday and month names are taken from configured tokens when available,
+    /// and phrases such as "yesterday" and "last" can be changed in config.phrases.
+    ///
+    /// :param target: Datetime to describe
+    /// :type target: datetime.datetime
+    /// :param now: Current time. Defaults to system time in UTC.
+    /// :type now: datetime.datetime, optional
+    /// :param weekday_start_mon: Whether weeks begin on Monday instead of Sunday. Defaults to True.
+    /// :type weekday_start_mon: bool, optional, default True
+    /// :rtype str
+    ///
+    #[pyfunction]
+    #[pyo3(
+        pass_module,
+        signature = (target, now=None, weekday_start_mon=true),
+        text_signature = "(target: datetime.datetime, now: datetime.datetime = None, weekday_start_mon: bool = True) -> str"
+    )]
+    fn to_calendar(
+        module: &Bound<'_, PyModule>,
+        py: Python,
+        target: Bound<PyDateTime>,
+        now: Option<Bound<PyDateTime>>,
+        weekday_start_mon: bool,
+    ) -> PyResult<String> {
+        let target_value = python::into_datetime(py, Some(target))?;
+        let date_value = python::into_datetime(py, now)?;
+        let fuzzy_date = build_fuzzy_date(module, date_value, weekday_start_mon)?;
+
+        py.detach(move || Ok(fuzzy_date.to_calendar(target_value)))
+    }
+
+    /// Describe datetime relative to current time, e.g. "3 days ago" or "in 2 hours"
+    ///
+    /// Current time (`now`) defaults to system time in UTC. Datetimes that do not
+    /// contain a timezone are assumed to be in UTC. Uses long unit names, e.g. "days",
+    /// from config.units_long, along with phrases such as "just now" and "yesterday"
+    /// from config.phrases.
+    ///
+    /// :param target: Datetime to describe
+    /// :type target: datetime.datetime
+    /// :param now: Current time. Defaults to system time in UTC.
+    /// :type now: datetime.datetime, optional
+    /// :rtype str
+    ///
+    #[pyfunction]
+    #[pyo3(
+        pass_module,
+        signature = (target, now=None),
+        text_signature = "(target: datetime.datetime, now: datetime.datetime = None) -> str"
+    )]
+    fn to_relative(
+        module: &Bound<'_, PyModule>,
+        py: Python,
+        target: Bound<PyDateTime>,
+        now: Option<Bound<PyDateTime>>,
+    ) -> PyResult<String> {
+        let target_value = python::into_datetime(py, Some(target))?;
+        let date_value = python::into_datetime(py, now)?;
+        let fuzzy_date = build_fuzzy_date(module, date_value, true)?;
+
+        py.detach(move || Ok(fuzzy_date.to_relative(target_value)))
     }
 
     /// Convert number of seconds into a time duration string
     ///
     /// Build a time duration string from number of seconds, e.g. 93600.0 is
     /// converted to "1d 2h". Maximum supported unit is weeks, minimum supported
-    /// unit is seconds. Units that have no value (are 0) are not shown.
+    /// unit is microseconds. Units that have no value (are 0) are not shown, and
+    /// the lowest unit shown keeps up to three decimals, e.g. "1.25sec".
     ///
     /// Returns an empty string if number of seconds is not enough for the
     /// lowest shown unit.
     ///
     /// :param source: Number of seconds
     /// :type source: float
-    /// :param unit: Unit type to use. Possible values are "long", "short" and None. Defaults to
-    ///              None. For example, "long" would display seconds as "seconds", short as "s" and
-    ///              default as "sec".
+    /// :param unit: Unit type to use. Possible values are "long", "short", "iso" and None. Defaults
+    ///              to None. For example, "long" would display seconds as "seconds", short as "s" and
+    ///              default as "sec", while "iso" would display an ISO 8601 duration, e.g. "PT1H4M".
     /// :type unit: str, optional
-    /// :param max: Maximum unit to show, defaults 'w' for weeks. Possible values are "s/sec" for
-    ///             seconds, "min/mins" for minutes, "h/hr/hrs" for hours, "d/day/days" for days
-    ///             and "w/week/weeks" for weeks.
+    /// :param max: Maximum unit to show, defaults 'w' for weeks. Possible values are "us" for
+    ///             microseconds, "ms" for milliseconds, "s/sec" for seconds, "min/mins" for minutes,
+    ///             "h/hr/hrs" for hours, "d/day/days" for days and "w/week/weeks" for weeks.
     /// :type max: str, optional, default "w"
-    /// :param min: Minimum unit to show, defaults 's' for seconds. Possible values are "s/sec" for
-    ///             seconds, "min/mins" for minutes, "h/hr/hrs" for hours, "d/day/days" for days
-    ///             and "w/week/weeks" for weeks.
+    /// :param min: Minimum unit to show, defaults 's' for seconds. Possible values are "us" for
+    ///             microseconds, "ms" for milliseconds, "s/sec" for seconds, "min/mins" for minutes,
+    ///             "h/hr/hrs" for hours, "d/day/days" for days and "w/week/weeks" for weeks.
+    ///             Strings with milliseconds or microseconds, e.g. "350ms", can be read back
+    ///             with to_seconds only when compact is True.
     /// :type min: str, optional, default "s"
+    /// :param sign: How negative durations are shown. Possible values are "relative" for
+    ///              "1hr ago" and "in 1hr", "custom" for the negative prefix and suffix from
+    ///              configured units, and "minus" or None for a leading minus, e.g. "-1hr".
+    ///              Defaults to None.
+    /// :type sign: str, optional
+    /// :param precision: Number of the most significant non-zero units to show, e.g. 2 for
+    ///                   "1w 1d" rather than "1w 1d 1hr 1min 1sec". Defaults to None, which
+    ///                   shows all units.
+    /// :type precision: int, optional
+    /// :param rounding: How the lowest unit shown is rounded when limiting the units with
+    ///                  precision. Possible values are "floor", "half_up", "ceil" and None.
+    ///                  Defaults to None, which rounds down.
+    /// :type rounding: str, optional
+    /// :raises ValueError
     /// :rtype str
     ///
     #[pyfunction]
     #[pyo3(
         pass_module,
-        signature = (seconds, units=None, max="w", min="s"),
-        text_signature = "(seconds: float, units: str = None, max: str = 'w', min: str = 's') -> str"
+        signature = (seconds, units=None, max="w", min="s", sign=None, precision=None, rounding=None),
+        text_signature = "(seconds: float, units: str = None, max: str = 'w', min: str = 's', sign: str = None, precision: int = None, rounding: str = None) -> str"
     )]
     fn to_duration(
         module: &Bound<'_, PyModule>,
@@ -514,8 +1039,13 @@ mod fuzzydate {
         units: Option<&str>,
         max: &str,
         min: &str,
+        sign: Option<&str>,
+        precision: Option<usize>,
+        rounding: Option<&str>,
     ) -> PyResult<String> {
         let unit_group = units.unwrap_or("");
+        let sign_style = python::into_choice(sign, "Sign style")?;
+        let rounding_mode = python::into_choice(rounding, "Rounding")?;
 
         let custom_units = match unit_group {
             "short" => read_config(module)?.units_short,
@@ -529,6 +1059,9 @@ mod fuzzydate {
                 .set_custom_units(custom_units)
                 .set_min_unit(min)
                 .set_max_unit(max)
+                .set_sign_style(sign_style)
+                .set_precision(precision.unwrap_or(0))
+                .set_rounding(rounding_mode)
                 .to_duration(seconds);
 
             Ok(result)
@@ -537,48 +1070,68 @@ mod fuzzydate {
 
     /// Turn time duration string into seconds
     ///
-    /// Only accepts exact time duration strings, such as "1h" rather than
-    /// "1 hour ago". Raises a ValueError if anything else than an exact
+    /// Only accepts exact time duration strings, such as "1h" or "PT1H" rather
+    /// than "1 hour ago". Raises a ValueError if anything else than an exact
     /// length of time is provided, or if years or months have been included.
     ///
     /// :param source: Source string
     /// :type source: str
+    /// :param compact: Read compact durations such as "1h30m45s" or "250ms", in which
+    ///                 "m" means minutes rather than months, defaults to False
+    /// :type compact: bool, optional, default False
     /// :raises ValueError
     /// :rtype float
     ///
     #[pyfunction]
     #[pyo3(
         pass_module,
-        signature = (source,),
-        text_signature = "(source: str) -> float"
+        signature = (source, compact=false),
+        text_signature = "(source: str, compact: bool = False) -> float"
     )]
-    fn to_seconds(module: &Bound<'_, PyModule>, py: Python, source: &str) -> PyResult<f64> {
-        let config_patterns = read_config(module)?.patterns;
-        let config_tokens = read_tokens(module)?;
+    fn to_seconds(module: &Bound<'_, PyModule>, py: Python, source: &str, compact: bool) -> PyResult<f64> {
+        let config = read_config(module)?;
+        let config_tokens = read_tokens(&config);
+        let config_patterns = config.patterns;
 
         py.detach(move || {
             let result = FuzzySeconds::new()
+                .set_compact(compact)
                 .set_custom_patterns(config_patterns)
                 .set_custom_tokens(config_tokens)
                 .to_seconds(source);
 
             match result {
                 Ok(v) => Ok(v),
-                Err(e) => Err(PyValueError::new_err(e)),
+                Err(e) => Err(python::into_error(e, source, "seconds")),
             }
         })
     }
 
     #[pymodule_init]
     fn init(module: &Bound<'_, PyModule>) -> PyResult<()> {
+        let py = module.py();
+        module.add("ParseError", py.get_type::<python::ParseError>())?;
+        module.add("UnknownTokenError", py.get_type::<python::UnknownTokenError>())?;
+        module.add("NoMatchingPatternError", py.get_type::<python::NoMatchingPatternError>())?;
+        module.add("InvalidDateError", py.get_type::<python::InvalidDateError>())?;
+        module.add("WeekdayMismatchError", py.get_type::<python::WeekdayMismatchError>())?;
+        module.add("OutOfRangeError", py.get_type::<python::OutOfRangeError>())?;
+        module.add("UnsupportedUnitError", py.get_type::<python::UnsupportedUnitError>())?;
+
         module.add(
             ATTR_CONFIG,
             Config {
                 patterns: HashMap::new(),
                 tokens: HashMap::new(),
+                day_periods: HashMap::new(),
+                fiscal_year_start: 1,
+                holidays: Vec::new(),
+                holiday_locale: String::from("us"),
+                phrases: PhraseNames::get_defaults(),
                 units: UnitNames::get_defaults(&UnitGroup::Default),
                 units_long: UnitNames::get_defaults(&UnitGroup::Long),
                 units_short: UnitNames::get_defaults(&UnitGroup::Short),
+                weekend_days: vec![6, 7],
             },
         )?;
 
@@ -592,16 +1145,50 @@ mod fuzzydate {
         Ok(Config {
             patterns: config.patterns.clone(),
             tokens: config.tokens.clone(),
+            day_periods: config.day_periods.clone(),
+            fiscal_year_start: config.fiscal_year_start,
+            holidays: config.holidays.clone(),
+            holiday_locale: config.holiday_locale.clone(),
+            phrases: config.phrases.clone(),
             units: config.units.clone(),
             units_long: config.units_long.clone(),
             units_short: config.units_short.clone(),
+            weekend_days: config.weekend_days.clone(),
         })
     }
 
-    /// Read custom tokens registered to Python module, and return
-    /// them as tokens the tokenization (currently) accepts
-    fn read_tokens(module: &Bound<'_, PyModule>) -> Result<HashMap<String, Token>, PyErr> {
+    /// Build conversion from current time, with all of the config registered
+    /// to Python module, reading the config only once
+    fn build_fuzzy_date(
+        module: &Bound<'_, PyModule>,
+        current_time: DateTime<FixedOffset>,
+        weekday_start_mon: bool,
+    ) -> Result<FuzzyDate, PyErr> {
         let config = read_config(module)?;
+        let config_tokens = read_tokens(&config);
+        let day_periods = read_day_periods(&config);
+
+        let week_start_day = match weekday_start_mon {
+            true => WeekStartDay::Monday,
+            false => WeekStartDay::Sunday,
+        };
+
+        Ok(FuzzyDate::from_time(current_time)
+            .set_first_weekday(week_start_day)
+            .set_fiscal_year_start(python::into_month(config.fiscal_year_start))
+            .set_day_periods(day_periods)
+            .set_weekend_days(python::into_weekend_days(&config.weekend_days))
+            .set_holiday_calendar(HolidayDates::new(config.holidays))
+            .set_holiday_rules(HolidayRules::from_locale(&config.holiday_locale).unwrap_or_default())
+            .set_custom_patterns(config.patterns)
+            .set_custom_tokens(config_tokens)
+            .set_custom_phrases(config.phrases)
+            .set_custom_units(config.units_long))
+    }
+
+    /// Read custom tokens from config, and return them as
+    /// tokens the tokenization (currently) accepts
+    fn read_tokens(config: &Config) -> HashMap<String, Token> {
         let mut result = HashMap::new();
 
         for (keyword, token_gid) in config.tokens.to_owned() {
@@ -610,6 +1197,20 @@ mod fuzzydate {
             }
         }
 
-        Ok(result)
+        result
+    }
+
+    /// Read times of named day periods from config, and
+    /// return them as hours and minutes
+    fn read_day_periods(config: &Config) -> HashMap<DayPeriod, (u32, u32)> {
+        let mut result = HashMap::new();
+
+        for (period, time) in config.day_periods.to_owned() {
+            if let (Ok(period), Some(time)) = (period.parse::<DayPeriod>(), python::into_hour_minute(&time)) {
+                result.insert(period, time);
+            }
+        }
+
+        result
     }
 }
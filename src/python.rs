@@ -1,6 +1,19 @@
-use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono::{DateTime, FixedOffset, Month, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc, Weekday};
+use fuzzy_date_rs::error;
+use fuzzy_date_rs::token::{TwoDigitYear, WeekendDays};
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
 use pyo3::types::{PyDate, PyDateTime};
 use pyo3::{Bound, Py, PyErr, Python};
+use std::str::FromStr;
+
+create_exception!(fuzzydate, ParseError, PyValueError, "Source string could not be converted");
+create_exception!(fuzzydate, UnknownTokenError, ParseError, "Source string contains an unknown word");
+create_exception!(fuzzydate, NoMatchingPatternError, ParseError, "Source string does not match any pattern");
+create_exception!(fuzzydate, InvalidDateError, ParseError, "Date or time does not exist");
+create_exception!(fuzzydate, WeekdayMismatchError, ParseError, "Weekday does not match the date");
+create_exception!(fuzzydate, OutOfRangeError, ParseError, "Value is out of range");
+create_exception!(fuzzydate, UnsupportedUnitError, ParseError, "Unit is not supported");
 
 /// Turn optional date from Python into DateTime with a timezone,
 /// setting UTC as timezone and time as midnight
@@ -35,9 +48,82 @@ pub(crate) fn into_datetime(py: Python, value: Option<Bound<PyDateTime>>) -> Res
     Ok(Utc.from_local_datetime(&naive_value).unwrap().fixed_offset())
 }
 
+/// Turn optional century pivot from Python into a policy for two-digit
+/// years, using the year closest to current time when missing
+pub(crate) fn into_two_digit_year(value: Option<u8>) -> TwoDigitYear {
+    match value {
+        Some(v) => TwoDigitYear::Pivot(v),
+        None => TwoDigitYear::Closest,
+    }
+}
+
+/// Turn optional choice from Python, e.g. "dmy", into its value, using the
+/// default when missing and raising a ValueError when it does not exist
+pub(crate) fn into_choice<T: FromStr + Default>(value: Option<&str>, name: &str) -> Result<T, PyErr> {
+    match value {
+        Some(v) => v
+            .parse::<T>()
+            .map_err(|_| PyValueError::new_err(format!("{} \"{}\" does not exist", name, v))),
+        None => Ok(T::default()),
+    }
+}
+
+/// Turn month number from Python into a month, using January
+/// when the month does not exist
+pub(crate) fn into_month(value: u32) -> Month {
+    u8::try_from(value)
+        .ok()
+        .and_then(|v| Month::try_from(v).ok())
+        .unwrap_or(Month::January)
+}
+
+/// Turn weekday numbers from Python, from 1 for Monday to 7 for Sunday,
+/// into weekend days, skipping numbers that are not weekdays
+pub(crate) fn into_weekend_days(values: &[u32]) -> WeekendDays {
+    let weekdays = values
+        .iter()
+        .filter_map(|v| u8::try_from(*v).ok())
+        .filter(|v| v.ge(&1))
+        .filter_map(|v| Weekday::try_from(v - 1).ok())
+        .collect::<Vec<Weekday>>();
+
+    WeekendDays::new(&weekdays)
+}
+
+/// Turn time of day from Python, e.g. "08:30", into hour and minute
+pub(crate) fn into_hour_minute(value: &str) -> Option<(u32, u32)> {
+    NaiveTime::parse_from_str(value, "%H:%M").ok().map(|v| (v.hour(), v.minute()))
+}
+
+/// Turn conversion error into a Python exception, with a message that
+/// describes what could not be converted and why
+pub(crate) fn into_error(from_error: error::ParseError, source: &str, target: &str) -> PyErr {
+    let message = match &from_error {
+        error::ParseError::NoMatchingPattern => format!("Unable to convert \"{}\" into {}", source, target),
+        error::ParseError::UnknownToken { span } => {
+            format!("Unable to convert \"{}\" into {}, unknown token \"{}\"", source, target, &source[span.to_owned()])
+        }
+        error::ParseError::UnsupportedUnit { unit } => match target {
+            "seconds" => format!("Converting {} into {} is not supported", unit, target),
+            _ => format!("Unable to convert \"{}\" into {}, unit {} is not supported", source, target, unit),
+        },
+        _ => format!("Unable to convert \"{}\" into {}, {}", source, target, from_error),
+    };
+
+    match from_error {
+        error::ParseError::UnknownToken { .. } => UnknownTokenError::new_err(message),
+        error::ParseError::NoMatchingPattern => NoMatchingPatternError::new_err(message),
+        error::ParseError::InvalidDate => InvalidDateError::new_err(message),
+        error::ParseError::WeekdayMismatch => WeekdayMismatchError::new_err(message),
+        error::ParseError::OutOfRange => OutOfRangeError::new_err(message),
+        error::ParseError::UnsupportedUnit { .. } => UnsupportedUnitError::new_err(message),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use fuzzy_date_rs::token::{DateOrder, Rounding, SignStyle};
     use pyo3::types::PyTzInfo;
     use pyo3::{Bound, IntoPyObject, PyResult, Python};
 
@@ -80,6 +166,113 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_into_two_digit_year() {
+        assert!(into_two_digit_year(Some(50)).eq(&TwoDigitYear::Pivot(50)));
+        assert!(into_two_digit_year(None).eq(&TwoDigitYear::Closest));
+    }
+
+    #[test]
+    fn test_into_choice() {
+        assert!(into_choice::<DateOrder>(Some("dmy"), "Date order").unwrap().eq(&DateOrder::Dmy));
+        assert!(into_choice::<DateOrder>(Some("YMD"), "Date order").unwrap().eq(&DateOrder::Ymd));
+        assert!(into_choice::<DateOrder>(None, "Date order").unwrap().eq(&DateOrder::Auto));
+
+        Python::initialize();
+
+        Python::attach(|py| {
+            let result_value = into_choice::<DateOrder>(Some("dym"), "Date order").err().unwrap();
+            assert!(result_value.is_instance_of::<PyValueError>(py));
+            assert_eq!(result_value.value(py).to_string(), "Date order \"dym\" does not exist");
+        });
+
+        assert!(
+            into_choice::<SignStyle>(Some("relative"), "Sign style")
+                .unwrap()
+                .eq(&SignStyle::Relative)
+        );
+        assert!(into_choice::<SignStyle>(None, "Sign style").unwrap().eq(&SignStyle::Minus));
+        assert!(
+            into_choice::<SignStyle>(Some("Relative"), "Sign style")
+                .unwrap()
+                .eq(&SignStyle::Relative)
+        );
+        assert!(into_choice::<SignStyle>(Some("ago"), "Sign style").is_err());
+
+        assert!(
+            into_choice::<Rounding>(Some("half_up"), "Rounding")
+                .unwrap()
+                .eq(&Rounding::HalfUp)
+        );
+        assert!(into_choice::<Rounding>(Some("CEIL"), "Rounding").unwrap().eq(&Rounding::Ceil));
+        assert!(into_choice::<Rounding>(None, "Rounding").unwrap().eq(&Rounding::Floor));
+        assert!(into_choice::<Rounding>(Some("round"), "Rounding").is_err());
+    }
+
+    #[test]
+    fn test_into_month() {
+        assert_eq!(into_month(1), Month::January);
+        assert_eq!(into_month(7), Month::July);
+        assert_eq!(into_month(13), Month::January);
+        assert_eq!(into_month(257), Month::January);
+    }
+
+    #[test]
+    fn test_into_weekend_days() {
+        assert_eq!(into_weekend_days(&[6, 7]), WeekendDays::default());
+        assert_eq!(into_weekend_days(&[5, 6]), WeekendDays::new(&[Weekday::Fri, Weekday::Sat]));
+        assert_eq!(into_weekend_days(&[0, 7, 8]), WeekendDays::new(&[Weekday::Sun]));
+    }
+
+    #[test]
+    fn test_into_hour_minute() {
+        assert_eq!(into_hour_minute("08:30"), Some((8, 30)));
+        assert_eq!(into_hour_minute("17:00"), Some((17, 0)));
+        assert_eq!(into_hour_minute("25:00"), None);
+        assert_eq!(into_hour_minute("morning"), None);
+    }
+
+    #[test]
+    fn test_into_error() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let result_value = into_error(error::ParseError::UnknownToken { span: 6..9 }, "1 day foo", "datetime");
+            assert!(result_value.is_instance_of::<UnknownTokenError>(py));
+            assert!(result_value.is_instance_of::<ParseError>(py));
+            assert!(result_value.is_instance_of::<PyValueError>(py));
+            assert_eq!(
+                result_value.value(py).to_string(),
+                "Unable to convert \"1 day foo\" into datetime, unknown token \"foo\""
+            );
+        });
+
+        Python::attach(|py| {
+            let result_value = into_error(error::ParseError::WeekdayMismatch, "Sun April 1 2023", "date");
+            assert!(result_value.is_instance_of::<WeekdayMismatchError>(py));
+            assert_eq!(
+                result_value.value(py).to_string(),
+                "Unable to convert \"Sun April 1 2023\" into date, weekday does not match the date"
+            );
+        });
+
+        Python::attach(|py| {
+            let result_value = into_error(error::ParseError::UnsupportedUnit { unit: "months" }, "1 month", "seconds");
+            assert!(result_value.is_instance_of::<UnsupportedUnitError>(py));
+            assert_eq!(result_value.value(py).to_string(), "Converting months into seconds is not supported");
+        });
+
+        Python::attach(|py| {
+            let result_value =
+                into_error(error::ParseError::UnsupportedUnit { unit: "weekends" }, "+1.5 weekends", "datetime");
+            assert!(result_value.is_instance_of::<UnsupportedUnitError>(py));
+            assert_eq!(
+                result_value.value(py).to_string(),
+                "Unable to convert \"+1.5 weekends\" into datetime, unit weekends is not supported"
+            );
+        });
+    }
+
     fn assert_date(py: Python, test_value: PyResult<Bound<PyDate>>, expect_value: &str) {
         let date_value: Bound<PyDate> = test_value.unwrap().into_pyobject(py).unwrap();
         let result_value = into_date(py, Some(date_value));